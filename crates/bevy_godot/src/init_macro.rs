@@ -3,9 +3,12 @@ use crate::prelude::{
     *,
 };
 use lazy_static::lazy_static;
-use std::sync::{
-    mpsc::{channel, Sender},
-    Mutex,
+use std::{
+    collections::BTreeMap,
+    sync::{
+        mpsc::{channel, Sender},
+        Mutex,
+    },
 };
 
 #[doc(hidden)]
@@ -61,6 +64,10 @@ impl Autoload {
                 .map_mut(|script, _base| script.notification_channel = Some(sender))
                 .unwrap();
             signal_watcher.base().set_name("GodotSignalWatcher");
+            SIGNAL_WATCHER_INSTANCE_ID.store(
+                signal_watcher.base().get_instance_id(),
+                std::sync::atomic::Ordering::Relaxed,
+            );
 
             base.add_child(signal_watcher.into_base().into_shared(), true);
 
@@ -80,6 +87,25 @@ impl Autoload {
             app.insert_non_send_resource(InputEventReader(reciever));
         }
 
+        {
+            let (sender, reciever) = channel();
+            let control_watcher = ControlWatcher::new_instance();
+            control_watcher
+                .map_mut(|script, _base| script.notification_channel = Some(sender))
+                .unwrap();
+            control_watcher.base().set_name("ControlWatcher");
+
+            base.add_child(control_watcher.into_base().into_shared(), true);
+
+            app.insert_non_send_resource(ControlChangedReader(reciever));
+        }
+
+        {
+            let (sender, reciever) = channel();
+            app.insert_non_send_resource(TransformChangedReader(reciever))
+                .insert_non_send_resource(TransformWatcherSender(sender));
+        }
+
         self.app = Some(app);
 
         // disable pausing on the autoload to allow bevy updates when paused
@@ -129,6 +155,9 @@ pub fn godot_init(init: &InitHandle) {
     init.add_class::<CollisionWatcher>();
     init.add_class::<signal_watcher::GodotSignalWatcher>();
     init.add_class::<InputEventWatcher>();
+    init.add_class::<ControlWatcher>();
+    init.add_class::<transform_watcher::TransformWatcher>();
+    init.add_class::<transform_watcher::TransformWatcher2D>();
 }
 
 #[derive(NativeClass, Default)]
@@ -162,6 +191,8 @@ impl SceneTreeWatcher {
 #[inherit(Node)]
 struct CollisionWatcher {
     notification_channel: Option<Sender<CollisionEvent>>,
+    /// The targets of the ongoing shape collisions, keyed by origin, target RID and shape indices
+    shape_targets: BTreeMap<(i64, Rid, i64, i64), i64>,
 }
 
 #[methods]
@@ -176,6 +207,7 @@ impl CollisionWatcher {
         target: Ref<Node>,
         origin: Ref<Node>,
         event_type: CollisionEventType,
+        kind: CollisionKind,
     ) {
         let (origin, target) = unsafe { (origin.assume_safe(), target.assume_safe()) };
         self.notification_channel
@@ -183,20 +215,100 @@ impl CollisionWatcher {
             .unwrap()
             .send(CollisionEvent {
                 event_type,
+                kind,
                 origin: origin.get_instance_id(),
                 target: target.get_instance_id(),
+                local_shape: None,
+                remote_shape: None,
+            })
+            .unwrap();
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[method]
+    fn shape_collision_event(
+        &mut self,
+        target_rid: Rid,
+        target: Option<Ref<Node>>,
+        remote_shape: i64,
+        local_shape: i64,
+        origin: Ref<Node>,
+        event_type: CollisionEventType,
+        kind: CollisionKind,
+    ) {
+        let origin = unsafe { origin.assume_safe() }.get_instance_id();
+        let key = (origin, target_rid, remote_shape, local_shape);
+
+        // the target is null when it was freed while colliding, so it's looked up by its RID. Targets that
+        // can't be found are sent with the null instance id and reported as unresolved.
+        let target = match (target, event_type) {
+            (Some(target), CollisionEventType::Started) => {
+                let target = unsafe { target.assume_safe() }.get_instance_id();
+                self.shape_targets.insert(key, target);
+                target
+            }
+            (target, CollisionEventType::Ended) => self
+                .shape_targets
+                .remove(&key)
+                .or_else(|| target.map(|target| unsafe { target.assume_safe() }.get_instance_id()))
+                .unwrap_or_default(),
+            (None, CollisionEventType::Started) => 0,
+        };
+
+        self.notification_channel
+            .as_ref()
+            .unwrap()
+            .send(CollisionEvent {
+                event_type,
+                kind,
+                origin,
+                target,
+                local_shape: Some(local_shape),
+                remote_shape: Some(remote_shape),
             })
             .unwrap();
     }
 }
 
+#[derive(NativeClass, Default)]
+#[inherit(Node)]
+struct ControlWatcher {
+    notification_channel: Option<Sender<i64>>,
+}
+
+#[methods]
+impl ControlWatcher {
+    fn new(_base: &Node) -> Self {
+        Self::default()
+    }
+
+    #[method]
+    fn control_event(&self, control: Ref<Node>) {
+        let control = unsafe { control.assume_safe() };
+        self.notification_channel
+            .as_ref()
+            .unwrap()
+            .send(control.get_instance_id())
+            .unwrap();
+    }
+}
+
 #[doc(hidden)]
 pub mod signal_watcher {
-    use crate::prelude::{bevy_prelude::trace, godot_prelude::*, *};
+    use crate::prelude::{
+        bevy_prelude::{error, trace},
+        godot_prelude::*,
+        *,
+    };
+    use gdnative::export::Varargs;
     use std::sync::mpsc::Sender;
 
+    /// The number of arguments bound when connecting a signal, appended by Godot after the signal's arguments
+    pub const BOUND_ARG_COUNT: usize = 2;
+
     #[derive(NativeClass, Default)]
     #[inherit(Node)]
+    #[register_with(Self::register)]
     pub struct GodotSignalWatcher {
         pub notification_channel: Option<Sender<GodotSignal>>,
     }
@@ -206,41 +318,36 @@ pub mod signal_watcher {
         fn new(_base: &Node) -> Self {
             Self::default()
         }
+    }
 
-        #[allow(clippy::too_many_arguments)]
-        #[method]
-        fn event(
-            &self,
-            #[base] base: TRef<Node>,
-            #[opt] arg_1: Option<Variant>,
-            #[opt] arg_2: Option<Variant>,
-            #[opt] arg_3: Option<Variant>,
-            #[opt] arg_4: Option<Variant>,
-            #[opt] arg_5: Option<Variant>,
-            #[opt] arg_6: Option<Variant>,
-            #[opt] arg_7: Option<Variant>,
-            #[opt] arg_8: Option<Variant>,
-            #[opt] arg_9: Option<Variant>,
-        ) {
-            let args = vec![
-                arg_1, arg_2, arg_3, arg_4, arg_5, arg_6, arg_7, arg_8, arg_9,
-            ]
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>();
-
-            let signal_args = args
-                .iter()
-                .take_while(|arg| **arg != Variant::new(base))
-                .cloned()
-                .collect::<Vec<_>>();
-            let origin = args[signal_args.len() + 1].clone();
-            let signal_name = args[signal_args.len() + 2].clone();
+    impl GodotSignalWatcher {
+        fn register(builder: &ClassBuilder<Self>) {
+            builder.method("event", SignalEventMethod).done_stateless();
+        }
+
+        fn event(&self, args: &[&Variant]) {
+            // godot passes the signal's arguments followed by the bound `[origin, signal_name]`
+            if args.len() < BOUND_ARG_COUNT {
+                error!(target: "godot_signal", "signal forwarded without its bound arguments");
+                return;
+            }
+            let (signal_args, bound_args) = args.split_at(args.len() - BOUND_ARG_COUNT);
+
+            let (origin, signal_name) = match (
+                bound_args[0].try_to_object::<Object>(),
+                bound_args[1].try_to::<String>(),
+            ) {
+                (Ok(origin), Ok(signal_name)) => (origin, signal_name),
+                _ => {
+                    error!(target: "godot_signal", "signal forwarded with malformed bound arguments");
+                    return;
+                }
+            };
 
             let signal_event = GodotSignal::new(
-                signal_name.try_to::<String>().unwrap(),
-                unsafe { origin.try_to_object::<Object>().unwrap().assume_safe() },
-                signal_args,
+                signal_name,
+                unsafe { origin.assume_safe() },
+                signal_args.iter().map(|arg| (*arg).clone()).collect(),
             );
 
             trace!(target: "godot_signal", signal = ?signal_event);
@@ -252,6 +359,114 @@ pub mod signal_watcher {
                 .unwrap();
         }
     }
+
+    /// Forwards signals of any arity to `GodotSignalWatcher::event`
+    #[derive(Clone, Copy, Default)]
+    struct SignalEventMethod;
+
+    impl Method<GodotSignalWatcher> for SignalEventMethod {
+        fn call(&self, this: TInstance<'_, GodotSignalWatcher>, args: Varargs<'_>) -> Variant {
+            this.map(|watcher, _base| watcher.event(args.as_slice()))
+                .unwrap();
+
+            Variant::nil()
+        }
+    }
+}
+
+#[doc(hidden)]
+pub mod transform_watcher {
+    use crate::prelude::{godot_prelude::*, *};
+    use std::sync::mpsc::Sender;
+
+    /// Metadata key marking watcher nodes, which are never mirrored into the ECS
+    pub const TRANSFORM_WATCHER_META: &str = "_bevy_transform_watcher";
+
+    /// Child of a `Spatial` that reports the instance id of its parent when the parent's transform changes
+    #[derive(NativeClass, Default)]
+    #[inherit(Spatial)]
+    pub struct TransformWatcher {
+        pub notification_channel: Option<Sender<i64>>,
+    }
+
+    #[methods]
+    impl TransformWatcher {
+        fn new(_base: &Spatial) -> Self {
+            Self::default()
+        }
+
+        #[method]
+        fn _ready(&self, #[base] base: &Spatial) {
+            // copies made by `Node.duplicate` aren't connected to the app, the copied node gets its own watcher
+            if self.notification_channel.is_none() {
+                base.queue_free();
+                return;
+            }
+
+            base.set_notify_transform(true);
+            base.global_transform();
+        }
+
+        #[method]
+        fn _notification(&self, #[base] base: &Spatial, what: i64) {
+            if what != Spatial::NOTIFICATION_TRANSFORM_CHANGED {
+                return;
+            }
+
+            // godot only notifies again once the invalidated global transform was read
+            base.global_transform();
+
+            if let (Some(channel), Some(parent)) =
+                (self.notification_channel.as_ref(), base.get_parent())
+            {
+                let parent = unsafe { parent.assume_safe() };
+                channel.send(parent.get_instance_id()).unwrap();
+            }
+        }
+    }
+
+    /// Child of a `Node2D` that reports the instance id of its parent when the parent's transform changes
+    #[derive(NativeClass, Default)]
+    #[inherit(Node2D)]
+    pub struct TransformWatcher2D {
+        pub notification_channel: Option<Sender<i64>>,
+    }
+
+    #[methods]
+    impl TransformWatcher2D {
+        fn new(_base: &Node2D) -> Self {
+            Self::default()
+        }
+
+        #[method]
+        fn _ready(&self, #[base] base: &Node2D) {
+            // copies made by `Node.duplicate` aren't connected to the app, the copied node gets its own watcher
+            if self.notification_channel.is_none() {
+                base.queue_free();
+                return;
+            }
+
+            base.set_notify_transform(true);
+            base.get_global_transform();
+        }
+
+        #[method]
+        fn _notification(&self, #[base] base: &Node2D, what: i64) {
+            if what != CanvasItem::NOTIFICATION_TRANSFORM_CHANGED {
+                return;
+            }
+
+            // godot only notifies again once the invalidated global transform was read
+            base.get_global_transform();
+
+            if let (Some(channel), Some(parent)) =
+                (self.notification_channel.as_ref(), base.get_parent())
+            {
+                let parent = unsafe { parent.assume_safe() };
+                channel.send(parent.get_instance_id()).unwrap();
+            }
+        }
+    }
 }
 
 #[derive(NativeClass, Default)]
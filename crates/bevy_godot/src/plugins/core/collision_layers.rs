@@ -0,0 +1,144 @@
+use crate::prelude::{
+    godot_prelude::{CollisionObject, CollisionObject2D, ProjectSettings},
+    *,
+};
+use bevy::ecs::system::EntityCommands;
+use std::collections::HashMap;
+
+pub struct GodotCollisionLayersPlugin;
+
+impl Plugin for GodotCollisionLayersPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(post_update_collision_layers.in_base_set(CoreSet::Last))
+            .init_resource::<PhysicsLayerNames>();
+    }
+}
+
+/// Bevy Component mirroring the `collision_layer` and `collision_mask` bits of a `CollisionObject` or `CollisionObject2D`
+///
+/// Read when the node is added to the scene tree and written back when changed.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CollisionLayers {
+    /// The layers the object is in
+    pub membership: u32,
+    /// The layers the object collides with
+    pub filter: u32,
+}
+
+impl CollisionLayers {
+    pub fn new(membership: u32, filter: u32) -> Self {
+        Self { membership, filter }
+    }
+
+    pub fn contains(&self, layer: u32) -> bool {
+        self.membership & layer != 0
+    }
+
+    pub fn interacts_with(&self, other: &CollisionLayers) -> bool {
+        self.filter & other.membership != 0
+    }
+}
+
+/// Bevy Resource with the physics layer names from the project settings
+///
+/// Names are read from `layer_names/2d_physics/layer_*` and `layer_names/3d_physics/layer_*`
+/// when the app is built, and map to the layer's bit.
+#[derive(Resource, Debug, Clone)]
+pub struct PhysicsLayerNames {
+    layers_2d: HashMap<String, u32>,
+    layers_3d: HashMap<String, u32>,
+}
+
+impl Default for PhysicsLayerNames {
+    fn default() -> Self {
+        Self {
+            layers_2d: read_layer_names("2d_physics"),
+            layers_3d: read_layer_names("3d_physics"),
+        }
+    }
+}
+
+impl PhysicsLayerNames {
+    /// Returns the bit of the named 2D physics layer
+    pub fn layer_2d(&self, name: &str) -> Option<u32> {
+        self.layers_2d.get(name).copied()
+    }
+
+    /// Returns the bit of the named 3D physics layer
+    pub fn layer_3d(&self, name: &str) -> Option<u32> {
+        self.layers_3d.get(name).copied()
+    }
+
+    /// Returns the bits of all the named 2D physics layers, or `None` if any isn't defined
+    pub fn layers_2d<'a>(&self, names: impl IntoIterator<Item = &'a str>) -> Option<u32> {
+        names
+            .into_iter()
+            .try_fold(0, |layers, name| Some(layers | self.layer_2d(name)?))
+    }
+
+    /// Returns the bits of all the named 3D physics layers, or `None` if any isn't defined
+    pub fn layers_3d<'a>(&self, names: impl IntoIterator<Item = &'a str>) -> Option<u32> {
+        names
+            .into_iter()
+            .try_fold(0, |layers, name| Some(layers | self.layer_3d(name)?))
+    }
+}
+
+fn read_layer_names(kind: &str) -> HashMap<String, u32> {
+    let project_settings = ProjectSettings::godot_singleton();
+
+    (1..=32)
+        .filter_map(|layer| {
+            let setting = format!("layer_names/{kind}/layer_{layer}");
+            if !project_settings.has_setting(setting.as_str()) {
+                return None;
+            }
+
+            let name = project_settings
+                .get_setting(setting.as_str())
+                .try_to::<String>()
+                .ok()
+                .filter(|name| !name.is_empty())?;
+
+            Some((name, 1 << (layer - 1)))
+        })
+        .collect()
+}
+
+/// Inserts the [`CollisionLayers`] of the node if it is a collision object
+pub(crate) fn insert_collision_layers(ent: &mut EntityCommands, node: TRef<Node>) {
+    if let Some(object) = node.cast::<CollisionObject2D>() {
+        ent.insert(CollisionLayers::new(
+            object.collision_layer() as u32,
+            object.collision_mask() as u32,
+        ));
+    } else if let Some(object) = node.cast::<CollisionObject>() {
+        ent.insert(CollisionLayers::new(
+            object.collision_layer() as u32,
+            object.collision_mask() as u32,
+        ));
+    }
+}
+
+fn post_update_collision_layers(
+    _scene_tree: SceneTreeRef,
+    mut entities: Query<(&CollisionLayers, &mut ErasedGodotRef), Changed<CollisionLayers>>,
+) {
+    for (layers, mut reference) in entities.iter_mut() {
+        if let Some(object) = reference.try_get::<CollisionObject2D>() {
+            if object.collision_layer() as u32 != layers.membership {
+                object.set_collision_layer(layers.membership as i64);
+            }
+            if object.collision_mask() as u32 != layers.filter {
+                object.set_collision_mask(layers.filter as i64);
+            }
+        } else if let Some(object) = reference.try_get::<CollisionObject>() {
+            if object.collision_layer() as u32 != layers.membership {
+                object.set_collision_layer(layers.membership as i64);
+            }
+            if object.collision_mask() as u32 != layers.filter {
+                object.set_collision_mask(layers.filter as i64);
+            }
+        }
+    }
+}
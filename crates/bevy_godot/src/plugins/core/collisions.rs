@@ -1,7 +1,9 @@
 use crate::prelude::{
-    godot_prelude::{FromVariant, ToVariant},
+    godot_prelude::{FromVariant, ToVariant, VariantArray},
     *,
 };
+use bevy::ecs::system::{EntityCommands, SystemParam};
+use std::marker::PhantomData;
 
 pub struct GodotCollisionsPlugin;
 
@@ -13,10 +15,19 @@ impl Plugin for GodotCollisionsPlugin {
                     .in_base_set(CoreSet::First)
                     .before(Events::<CollisionEvent>::update_system),
             )
-            .add_event::<CollisionEvent>();
+            .add_event::<CollisionEvent>()
+            .add_event::<CollisionStarted>()
+            .add_event::<CollisionEnded>()
+            .add_event::<ShapeCollisionStarted>()
+            .add_event::<ShapeCollisionEnded>()
+            .add_event::<UnresolvedCollision>();
     }
 }
 
+/// Bevy Component with the bodies colliding with a `CollisionObject`, from its `body_entered` and `body_exited` signals
+///
+/// A `RigidBody` only reports collisions with `contact_monitor` enabled and `contacts_reported` above zero,
+/// the colliding shapes are reported by [`ShapeCollisionStarted`] and [`ShapeCollisionEnded`].
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Component, Default)]
 pub struct Collisions {
     colliding_entities: Vec<Entity>,
@@ -33,21 +44,158 @@ impl Collisions {
     }
 }
 
+/// Bevy Component with the areas overlapping an `Area`, from its `area_entered` and `area_exited` signals
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Component, Default)]
+pub struct AreaOverlaps {
+    overlapping_entities: Vec<Entity>,
+    recent_overlaps: Vec<Entity>,
+}
+
+impl AreaOverlaps {
+    pub fn overlapping(&self) -> &[Entity] {
+        &self.overlapping_entities
+    }
+
+    pub fn recent_overlaps(&self) -> &[Entity] {
+        &self.recent_overlaps
+    }
+}
+
 #[doc(hidden)]
 pub struct CollisionEventReader(pub std::sync::mpsc::Receiver<CollisionEvent>);
 
-#[doc(hidden)]
-#[derive(ToVariant, FromVariant, Debug)]
+/// Whether a collision started or ended
+#[derive(ToVariant, FromVariant, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CollisionEventType {
     Started,
     Ended,
 }
 
+/// The signal a [`CollisionEvent`] was reported by
+#[derive(ToVariant, FromVariant, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollisionKind {
+    /// `body_entered` or `body_exited`
+    Body,
+    /// `area_entered` or `area_exited`
+    Area,
+    /// `body_shape_*` or `area_shape_*`, reported alongside the `Body` or `Area` event with the shape indices
+    Shape,
+}
+
 #[derive(Debug)]
 pub struct CollisionEvent {
     pub event_type: CollisionEventType,
+    pub kind: CollisionKind,
     pub origin: i64,
     pub target: i64,
+    /// The index of the origin's shape, only set for [`CollisionKind::Shape`] events
+    pub local_shape: Option<i64>,
+    /// The index of the target's shape, only set for [`CollisionKind::Shape`] events
+    pub remote_shape: Option<i64>,
+}
+
+/// Bevy Event sent when `b` starts colliding with or overlapping `a`, after the [`Collisions`] and [`AreaOverlaps`] are updated
+///
+/// `a` is the entity whose node reported the collision, so collisions reported by both nodes are sent twice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CollisionStarted {
+    pub a: Entity,
+    pub b: Entity,
+}
+
+/// Bevy Event sent when `b` stops colliding with or overlapping `a`, see [`CollisionStarted`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CollisionEnded {
+    pub a: Entity,
+    pub b: Entity,
+}
+
+/// Bevy Event sent when the `remote_shape` of `b` starts colliding with or overlapping the `local_shape` of `a`
+///
+/// Sent for the `body_shape_entered` and `area_shape_entered` signals, alongside [`CollisionStarted`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ShapeCollisionStarted {
+    pub a: Entity,
+    pub b: Entity,
+    pub local_shape: i64,
+    pub remote_shape: i64,
+}
+
+/// Bevy Event sent when the `remote_shape` of `b` stops colliding with or overlapping the `local_shape` of `a`,
+/// see [`ShapeCollisionStarted`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ShapeCollisionEnded {
+    pub a: Entity,
+    pub b: Entity,
+    pub local_shape: i64,
+    pub remote_shape: i64,
+}
+
+/// Bevy Event sent for a [`CollisionEvent`] whose origin or target isn't mirrored by an entity
+///
+/// This happens for collisions with nodes excluded by the [`SceneTreeConfig`] or freed before the collision started.
+/// Shape collisions whose target couldn't be identified are reported with a `target` of 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnresolvedCollision {
+    pub event_type: CollisionEventType,
+    pub kind: CollisionKind,
+    pub origin: i64,
+    pub target: i64,
+    pub origin_entity: Option<Entity>,
+    pub target_entity: Option<Entity>,
+    pub local_shape: Option<i64>,
+    pub remote_shape: Option<i64>,
+}
+
+/// Connects the collision signals of the node to the collision watcher and inserts [`Collisions`] and [`AreaOverlaps`]
+pub(crate) fn connect_collision_signals(
+    ent: &mut EntityCommands,
+    node: TRef<Node>,
+    collision_watcher: TRef<Node>,
+) {
+    use CollisionEventType::{Ended, Started};
+    use CollisionKind::{Area, Body, Shape};
+
+    let signals = [
+        ("body_entered", Started, Body),
+        ("body_exited", Ended, Body),
+        ("area_entered", Started, Area),
+        ("area_exited", Ended, Area),
+        ("body_shape_entered", Started, Shape),
+        ("body_shape_exited", Ended, Shape),
+        ("area_shape_entered", Started, Shape),
+        ("area_shape_exited", Ended, Shape),
+    ];
+
+    for (signal, event_type, kind) in signals {
+        let method = match kind {
+            Shape => "shape_collision_event",
+            Body | Area => "collision_event",
+        };
+
+        if !node.has_signal(signal) || node.is_connected(signal, collision_watcher, method) {
+            continue;
+        }
+
+        debug!(target: "godot_scene_tree_collisions", body_id = node.get_instance_id(), "has {} signal", signal);
+        let binds = VariantArray::from_iter(&[
+            Variant::new(node.claim()),
+            Variant::new(event_type),
+            Variant::new(kind),
+        ]);
+
+        if let Err(e) = node.connect(signal, collision_watcher, method, binds.into_shared(), 0) {
+            error!(target: "godot_scene_tree_collisions", body_id = node.get_instance_id(), "failed to connect {}: {}", signal, e);
+        }
+    }
+
+    if node.has_signal("body_entered") {
+        ent.insert(Collisions::default());
+    }
+
+    if node.has_signal("area_entered") {
+        ent.insert(AreaOverlaps::default());
+    }
 }
 
 fn write_godot_collision_events(
@@ -57,44 +205,120 @@ fn write_godot_collision_events(
     event_writer.send_batch(events.0.try_iter());
 }
 
+#[derive(SystemParam)]
+struct CollisionEventWriters<'w, 's> {
+    started: EventWriter<'w, CollisionStarted>,
+    ended: EventWriter<'w, CollisionEnded>,
+    shape_started: EventWriter<'w, ShapeCollisionStarted>,
+    shape_ended: EventWriter<'w, ShapeCollisionEnded>,
+    unresolved: EventWriter<'w, UnresolvedCollision>,
+    #[system_param(ignore)]
+    phantom: PhantomData<&'s ()>,
+}
+
 fn update_godot_collisions(
     mut events: EventReader<CollisionEvent>,
-    mut entities: Query<(&ErasedGodotRef, &mut Collisions)>,
-    all_entities: Query<(Entity, &ErasedGodotRef)>,
+    mut entities: Query<(Option<&mut Collisions>, Option<&mut AreaOverlaps>)>,
+    entity_map: Res<GodotEntityMap>,
+    mut writers: CollisionEventWriters,
 ) {
-    for (_, mut collisions) in entities.iter_mut() {
-        collisions.recent_collisions = vec![];
+    for (collisions, overlaps) in entities.iter_mut() {
+        if let Some(mut collisions) = collisions {
+            collisions.recent_collisions = vec![];
+        }
+        if let Some(mut overlaps) = overlaps {
+            overlaps.recent_overlaps = vec![];
+        }
     }
 
     for event in events.iter() {
         trace!(target: "godot_collisions_update", event = ?event);
 
-        let target = all_entities.iter().find_map(|(ent, reference)| {
-            if reference.instance_id() == event.target {
-                Some(ent)
-            } else {
-                None
+        // the node of a body freed while colliding is already unmapped when its collision ends
+        let resolve = |instance_id| match event.event_type {
+            CollisionEventType::Started => entity_map.get_entity(instance_id),
+            CollisionEventType::Ended => entity_map
+                .get_entity(instance_id)
+                .or_else(|| entity_map.get_removed_entity(instance_id)),
+        };
+
+        let (origin, target) = match (resolve(event.origin), resolve(event.target)) {
+            (Some(origin), Some(target)) => (origin, target),
+            (origin_entity, target_entity) => {
+                trace!(target: "godot_collisions_update", event = ?event, "unresolved collision");
+                writers.unresolved.send(UnresolvedCollision {
+                    event_type: event.event_type,
+                    kind: event.kind,
+                    origin: event.origin,
+                    target: event.target,
+                    origin_entity,
+                    target_entity,
+                    local_shape: event.local_shape,
+                    remote_shape: event.remote_shape,
+                });
+                continue;
             }
-        });
-        let collisions = entities.iter_mut().find_map(|(reference, collisions)| {
-            if reference.instance_id() == event.origin {
-                Some(collisions)
-            } else {
-                None
+        };
+
+        // shape events are sent alongside the body and area events, which update the components
+        if let (Some(local_shape), Some(remote_shape)) = (event.local_shape, event.remote_shape) {
+            match event.event_type {
+                CollisionEventType::Started => writers.shape_started.send(ShapeCollisionStarted {
+                    a: origin,
+                    b: target,
+                    local_shape,
+                    remote_shape,
+                }),
+                CollisionEventType::Ended => writers.shape_ended.send(ShapeCollisionEnded {
+                    a: origin,
+                    b: target,
+                    local_shape,
+                    remote_shape,
+                }),
             }
-        });
+            continue;
+        }
 
-        let (target, mut collisions) = match (target, collisions) {
-            (Some(target), Some(collisions)) => (target, collisions),
-            _ => return,
+        match event.event_type {
+            CollisionEventType::Started => writers.started.send(CollisionStarted {
+                a: origin,
+                b: target,
+            }),
+            CollisionEventType::Ended => writers.ended.send(CollisionEnded {
+                a: origin,
+                b: target,
+            }),
+        }
+
+        let (collisions, overlaps) = match entities.get_mut(origin) {
+            Ok(components) => components,
+            Err(_) => continue,
+        };
+
+        let (colliding, recent) = match (event.kind, collisions, overlaps) {
+            (CollisionKind::Body, Some(collisions), _) => {
+                let collisions = collisions.into_inner();
+                (
+                    &mut collisions.colliding_entities,
+                    &mut collisions.recent_collisions,
+                )
+            }
+            (CollisionKind::Area, _, Some(overlaps)) => {
+                let overlaps = overlaps.into_inner();
+                (
+                    &mut overlaps.overlapping_entities,
+                    &mut overlaps.recent_overlaps,
+                )
+            }
+            _ => continue,
         };
 
         match event.event_type {
             CollisionEventType::Started => {
-                collisions.colliding_entities.push(target);
-                collisions.recent_collisions.push(target);
+                colliding.push(target);
+                recent.push(target);
             }
-            CollisionEventType::Ended => collisions.colliding_entities.retain(|x| *x != target),
+            CollisionEventType::Ended => colliding.retain(|x| *x != target),
         };
     }
 }
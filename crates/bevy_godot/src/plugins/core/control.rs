@@ -0,0 +1,224 @@
+use crate::prelude::{
+    godot_prelude::{GlobalConstants, VariantArray},
+    *,
+};
+use bevy::ecs::system::EntityCommands;
+use std::{collections::HashSet, sync::mpsc::Receiver};
+
+pub struct GodotControlPlugin;
+
+impl Plugin for GodotControlPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(pre_update_control_rects.in_base_set(CoreSet::PreUpdate))
+            .add_system(post_update_control_rects.in_base_set(CoreSet::Last));
+    }
+}
+
+/// Values for the left, top, right and bottom sides of a `Control`
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct RectSides {
+    pub left: f32,
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+}
+
+impl RectSides {
+    pub fn new(left: f32, top: f32, right: f32, bottom: f32) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    fn sides(&self) -> [(i64, f32); 4] {
+        [
+            (GlobalConstants::MARGIN_LEFT, self.left),
+            (GlobalConstants::MARGIN_TOP, self.top),
+            (GlobalConstants::MARGIN_RIGHT, self.right),
+            (GlobalConstants::MARGIN_BOTTOM, self.bottom),
+        ]
+    }
+}
+
+/// Bevy Component mirroring the layout of a `Control`
+///
+/// Read from the node in the PreUpdate stage after it emits `item_rect_changed` or `minimum_size_changed`, or when
+/// its anchors changed, which Godot doesn't signal while the rect stays in place. Written back in the Last stage
+/// when changed.
+///
+/// Only the fields changed in Bevy are written: changing `anchors` keeps the rect in place, and changing
+/// `position` or `size` recomputes the `margins`, as in the Godot editor.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct ControlRect {
+    /// `rect_position`, relative to the parent
+    pub position: Vec2,
+    /// `rect_size`
+    pub size: Vec2,
+    /// `rect_min_size`
+    pub min_size: Vec2,
+    /// Anchors as fractions of the parent's size
+    pub anchors: RectSides,
+    /// Margins as offsets from the anchors, in pixels
+    pub margins: RectSides,
+}
+
+impl ControlRect {
+    fn read(control: TRef<Control>) -> Self {
+        let vec2 = |vector: Vector2| Vec2::new(vector.x, vector.y);
+        let margin = |margin| control.margin(margin) as f32;
+
+        Self {
+            position: vec2(control.position()),
+            size: vec2(control.size()),
+            min_size: vec2(control.custom_minimum_size()),
+            anchors: Self::read_anchors(control),
+            margins: RectSides::new(
+                margin(GlobalConstants::MARGIN_LEFT),
+                margin(GlobalConstants::MARGIN_TOP),
+                margin(GlobalConstants::MARGIN_RIGHT),
+                margin(GlobalConstants::MARGIN_BOTTOM),
+            ),
+        }
+    }
+
+    fn read_anchors(control: TRef<Control>) -> RectSides {
+        let anchor = |margin| control.anchor(margin) as f32;
+
+        RectSides::new(
+            anchor(GlobalConstants::MARGIN_LEFT),
+            anchor(GlobalConstants::MARGIN_TOP),
+            anchor(GlobalConstants::MARGIN_RIGHT),
+            anchor(GlobalConstants::MARGIN_BOTTOM),
+        )
+    }
+}
+
+/// The [`ControlRect`] as last read from or written to the node, to tell which fields were changed in Bevy
+#[derive(Component, Debug, Clone, Copy)]
+struct SyncedControlRect(ControlRect);
+
+#[doc(hidden)]
+pub struct ControlChangedReader(pub Receiver<i64>);
+
+/// Inserts the [`ControlRect`] of the node if it is a `Control` and connects its layout signals to the control watcher
+pub(crate) fn insert_control_rect(
+    ent: &mut EntityCommands,
+    node: TRef<Node>,
+    control_watcher: TRef<Node>,
+) {
+    let control = match node.cast::<Control>() {
+        Some(control) => control,
+        None => return,
+    };
+
+    for signal in ["item_rect_changed", "minimum_size_changed"] {
+        if control.is_connected(signal, control_watcher, "control_event") {
+            continue;
+        }
+
+        let binds = VariantArray::from_iter(&[Variant::new(node.claim())]);
+        if let Err(e) = control.connect(
+            signal,
+            control_watcher,
+            "control_event",
+            binds.into_shared(),
+            0,
+        ) {
+            error!(
+                control_id = node.get_instance_id(),
+                "failed to connect {}: {}", signal, e
+            );
+        }
+    }
+
+    let rect = ControlRect::read(control);
+    ent.insert(rect).insert(SyncedControlRect(rect));
+}
+
+fn pre_update_control_rects(
+    _scene_tree: SceneTreeRef,
+    reader: NonSendMut<ControlChangedReader>,
+    entity_map: Res<GodotEntityMap>,
+    mut entities: Query<(
+        Entity,
+        &mut ControlRect,
+        &mut SyncedControlRect,
+        &mut ErasedGodotRef,
+    )>,
+) {
+    let changed = reader
+        .0
+        .try_iter()
+        .filter_map(|id| entity_map.get_entity(id))
+        .collect::<HashSet<_>>();
+
+    for (ent, mut rect, mut synced, mut reference) in entities.iter_mut() {
+        let control = match reference.try_get::<Control>() {
+            Some(control) => control,
+            None => continue,
+        };
+
+        // anchor changes that keep the rect in place aren't signaled, so the anchors are compared every frame
+        if !changed.contains(&ent) && ControlRect::read_anchors(control) == synced.0.anchors {
+            continue;
+        }
+
+        let control_rect = ControlRect::read(control);
+        if *rect != control_rect {
+            *rect = control_rect;
+        }
+        synced.0 = control_rect;
+    }
+}
+
+fn post_update_control_rects(
+    _scene_tree: SceneTreeRef,
+    mut entities: Query<
+        (
+            &mut ControlRect,
+            &mut SyncedControlRect,
+            &mut ErasedGodotRef,
+        ),
+        Changed<ControlRect>,
+    >,
+) {
+    for (mut rect, mut synced, mut reference) in entities.iter_mut() {
+        let control = match reference.try_get::<Control>() {
+            Some(control) => control,
+            None => continue,
+        };
+
+        if rect.anchors != synced.0.anchors {
+            // pushing the opposite anchor keeps godot from clamping a begin anchor to the old end anchor
+            for (side, anchor) in rect.anchors.sides() {
+                control.set_anchor(side, anchor as f64, false, true);
+            }
+        }
+
+        if rect.margins != synced.0.margins {
+            for (side, margin) in rect.margins.sides() {
+                control.set_margin(side, margin as f64);
+            }
+        }
+
+        if rect.min_size != synced.0.min_size {
+            control.set_custom_minimum_size(Vector2::new(rect.min_size.x, rect.min_size.y));
+        }
+
+        if rect.position != synced.0.position {
+            control.set_position(Vector2::new(rect.position.x, rect.position.y), false);
+        }
+
+        if rect.size != synced.0.size {
+            control.set_size(Vector2::new(rect.size.x, rect.size.y), false);
+        }
+
+        // the other fields are derived from the ones that were set
+        let control_rect = ControlRect::read(control);
+        *rect.bypass_change_detection() = control_rect;
+        synced.0 = control_rect;
+    }
+}
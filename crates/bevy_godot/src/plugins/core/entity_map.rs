@@ -0,0 +1,103 @@
+use crate::prelude::*;
+use std::collections::HashMap;
+
+/// Bevy Resource that maps Godot instance ids to their mirrored entities and back
+///
+/// It is maintained by the [`GodotSceneTreePlugin`] as nodes are added to and removed from the scene tree
+/// and as [`ErasedGodotRef`]s are inserted on or removed from entities.
+#[derive(Resource, Debug, Default)]
+pub struct GodotEntityMap {
+    entities: HashMap<i64, Entity>,
+    instance_ids: HashMap<Entity, i64>,
+    /// Pairs removed since the start of the update, to resolve events reported for freed nodes
+    removed: HashMap<i64, Entity>,
+}
+
+impl GodotEntityMap {
+    /// Returns the entity mirroring the Godot object with the given instance id
+    pub fn get_entity(&self, instance_id: i64) -> Option<Entity> {
+        self.entities.get(&instance_id).copied()
+    }
+
+    /// Returns the instance id of the Godot object mirrored by the given entity
+    pub fn get_instance_id(&self, entity: Entity) -> Option<i64> {
+        self.instance_ids.get(&entity).copied()
+    }
+
+    pub fn contains_instance_id(&self, instance_id: i64) -> bool {
+        self.entities.contains_key(&instance_id)
+    }
+
+    pub fn contains_entity(&self, entity: Entity) -> bool {
+        self.instance_ids.contains_key(&entity)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (i64, Entity)> + '_ {
+        self.entities
+            .iter()
+            .map(|(instance_id, ent)| (*instance_id, *ent))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    pub(crate) fn insert(&mut self, instance_id: i64, ent: Entity) {
+        if let Some(old_ent) = self.entities.insert(instance_id, ent) {
+            if old_ent != ent {
+                self.instance_ids.remove(&old_ent);
+            }
+        }
+
+        if let Some(old_instance_id) = self.instance_ids.insert(ent, instance_id) {
+            if old_instance_id != instance_id {
+                self.entities.remove(&old_instance_id);
+            }
+        }
+    }
+
+    pub(crate) fn remove_instance_id(&mut self, instance_id: i64) -> Option<Entity> {
+        let ent = self.entities.remove(&instance_id)?;
+        self.instance_ids.remove(&ent);
+        self.removed.insert(instance_id, ent);
+        Some(ent)
+    }
+
+    pub(crate) fn remove_entity(&mut self, ent: Entity) -> Option<i64> {
+        let instance_id = self.instance_ids.remove(&ent)?;
+        self.entities.remove(&instance_id);
+        self.removed.insert(instance_id, ent);
+        Some(instance_id)
+    }
+
+    /// Returns the entity that mirrored the Godot object until it was removed in this update
+    pub(crate) fn get_removed_entity(&self, instance_id: i64) -> Option<Entity> {
+        self.removed.get(&instance_id).copied()
+    }
+}
+
+pub(crate) fn clear_removed_godot_refs(mut entity_map: ResMut<GodotEntityMap>) {
+    entity_map.removed.clear();
+}
+
+pub(crate) fn index_added_godot_refs(
+    mut entity_map: ResMut<GodotEntityMap>,
+    entities: Query<(Entity, &ErasedGodotRef), Added<ErasedGodotRef>>,
+) {
+    for (ent, reference) in entities.iter() {
+        entity_map.insert(reference.instance_id(), ent);
+    }
+}
+
+pub(crate) fn unindex_removed_godot_refs(
+    mut entity_map: ResMut<GodotEntityMap>,
+    mut removed: RemovedComponents<ErasedGodotRef>,
+) {
+    for ent in removed.iter() {
+        entity_map.remove_entity(ent);
+    }
+}
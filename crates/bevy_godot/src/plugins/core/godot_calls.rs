@@ -0,0 +1,140 @@
+use crate::prelude::{godot_prelude::OwnedToVariant, *};
+use bevy::ecs::system::SystemState;
+
+pub struct GodotCallsPlugin;
+
+impl Plugin for GodotCallsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(process_godot_calls.in_base_set(CoreSet::Last))
+            .add_event::<EmitGodotSignal>()
+            .add_event::<CallGodotMethod>();
+    }
+}
+
+/// Bevy Event that emits a signal on the entity's node
+///
+/// Processed in the Last stage by an exclusive system, so systems sending it don't need access to the scene tree.
+#[derive(Debug, Clone)]
+pub struct EmitGodotSignal {
+    pub entity: Entity,
+    pub name: String,
+    pub args: Vec<Variant>,
+}
+
+impl EmitGodotSignal {
+    pub fn new(entity: Entity, name: impl ToString) -> Self {
+        Self {
+            entity,
+            name: name.to_string(),
+            args: vec![],
+        }
+    }
+
+    pub fn with_arg(mut self, arg: impl OwnedToVariant) -> Self {
+        self.args.push(arg.owned_to_variant());
+        self
+    }
+}
+
+/// Bevy Event that calls a method on the entity's node
+///
+/// Processed in the Last stage by an exclusive system, so systems sending it don't need access to the scene tree.
+/// Deferred calls are made by Godot at the end of its frame.
+#[derive(Debug, Clone)]
+pub struct CallGodotMethod {
+    pub entity: Entity,
+    pub method: String,
+    pub args: Vec<Variant>,
+    pub deferred: bool,
+}
+
+impl CallGodotMethod {
+    pub fn new(entity: Entity, method: impl ToString) -> Self {
+        Self {
+            entity,
+            method: method.to_string(),
+            args: vec![],
+            deferred: false,
+        }
+    }
+
+    pub fn with_arg(mut self, arg: impl OwnedToVariant) -> Self {
+        self.args.push(arg.owned_to_variant());
+        self
+    }
+
+    pub fn deferred(mut self) -> Self {
+        self.deferred = true;
+        self
+    }
+}
+
+/// Returns the node of the entity for `action`, warning when the entity has no node or it was freed
+pub(crate) fn get_entity_node<'a>(
+    entities: &'a mut Query<&mut ErasedGodotRef>,
+    entity: Entity,
+    action: std::fmt::Arguments,
+) -> Option<TRef<'a, Object>> {
+    let reference = match entities.get_mut(entity) {
+        Ok(reference) => reference.into_inner(),
+        Err(_) => {
+            warn!(entity = ?entity, "cannot {action} without a godot node");
+            return None;
+        }
+    };
+
+    match reference.checked_get::<Object>() {
+        Ok(node) => Some(node),
+        Err(e) => {
+            warn!(entity = ?entity, "cannot {action}: {e}");
+            None
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn process_godot_calls(
+    world: &mut World,
+    state: &mut SystemState<(
+        EventReader<EmitGodotSignal>,
+        EventReader<CallGodotMethod>,
+        Query<&mut ErasedGodotRef>,
+    )>,
+) {
+    let (mut signals, mut calls, mut entities) = state.get_mut(world);
+
+    for signal in signals.iter() {
+        if let Some(node) = get_entity_node(
+            &mut entities,
+            signal.entity,
+            format_args!("emit signal {}", signal.name),
+        ) {
+            node.emit_signal(signal.name.as_str(), &signal.args);
+        }
+    }
+
+    for call in calls.iter() {
+        let node = match get_entity_node(
+            &mut entities,
+            call.entity,
+            format_args!("call method {}", call.method),
+        ) {
+            Some(node) => node,
+            None => continue,
+        };
+
+        if !node.has_method(call.method.as_str()) {
+            warn!(entity = ?call.entity, "{} has no method {}", node.get_class(), call.method);
+            continue;
+        }
+
+        // SAFETY: the exclusive system runs on the main thread and the node was checked to be alive
+        unsafe {
+            if call.deferred {
+                node.call_deferred(call.method.as_str(), &call.args);
+            } else {
+                node.call(call.method.as_str(), &call.args);
+            }
+        }
+    }
+}
@@ -7,28 +7,93 @@ use gdnative::{
     },
     prelude::{GodotObject, ManuallyManaged, Object, Ref, SubClass, TRef},
 };
+use lazy_static::lazy_static;
+use std::{borrow::Cow, collections::HashSet, sync::Mutex};
 
+/// A reference to a manually managed Godot object by its instance id
+///
+/// Every access looks the object up by its instance id, so a freed object is reported instead of being used.
+///
+/// # Safety
+/// Godot objects may only be used from the main thread, but this type is a `Send + Sync` Bevy Component and
+/// can't tell which thread it is accessed from without passing a proof of being on the main thread to every
+/// access. The constructors are `unsafe` instead, and by calling them the caller promises that the object is
+/// only accessed through the returned reference and its clones from the main thread, e.g. in systems taking a
+/// [`SceneTreeRef`], which Bevy runs exclusively on the main thread.
 #[derive(Component, Reflect, Clone, Default, Debug, TypeUuid)]
 #[reflect(Component)]
 #[uuid = "1908221d-5b6a-4921-b831-4da0c2b8d182"]
 pub struct ErasedGodotRef {
     object_id: i64,
-    class_name: String,
+    /// Borrowed from the interned class names, so that clones don't allocate
+    class_name: Cow<'static, str>,
+}
+
+lazy_static! {
+    static ref CLASS_NAMES: Mutex<HashSet<&'static str>> = Mutex::new(HashSet::new());
+}
+
+/// Returns the class name with a static lifetime, leaking each class name once since there are only so many classes
+fn intern_class_name(class_name: String) -> &'static str {
+    let mut class_names = CLASS_NAMES.lock().unwrap();
+    match class_names.get(class_name.as_str()) {
+        Some(class_name) => class_name,
+        None => {
+            let class_name = Box::leak(class_name.into_boxed_str());
+            class_names.insert(class_name);
+            class_name
+        }
+    }
 }
 
 impl ErasedGodotRef {
     pub fn get<T: GodotObject<Memory = ManuallyManaged>>(&mut self) -> TRef<T> {
-        self.try_get()
-            .unwrap_or_else(|| panic!("failed to get godot ref as {}", std::any::type_name::<T>()))
+        self.checked_get().unwrap_or_else(|e| {
+            panic!(
+                "failed to get godot ref as {}: {}",
+                std::any::type_name::<T>(),
+                e
+            )
+        })
     }
 
     pub fn try_get<T: GodotObject<Memory = ManuallyManaged>>(&mut self) -> Option<TRef<T>> {
-        // SAFETY: The caller must uphold the contract of the constructors to ensure exclusive access
-        unsafe { TRef::try_from_instance_id(self.object_id) }
+        self.checked_get().ok()
+    }
+
+    /// Returns the object as `T`, checking that it is still alive and of the expected class
+    ///
+    /// Godot 3 doesn't reuse instance ids, so a live object behind the instance id is the referenced object.
+    pub fn checked_get<T: GodotObject<Memory = ManuallyManaged>>(
+        &mut self,
+    ) -> Result<TRef<T>, GodotRefError> {
+        // SAFETY: The constructors' callers promised to only access the object from the main thread
+        if let Some(obj) = unsafe { TRef::try_from_instance_id(self.object_id) } {
+            return Ok(obj);
+        }
+
+        // only tell the failures apart once the lookup failed, to keep the common path to a single lookup
+        match unsafe { TRef::<Object>::try_from_instance_id(self.object_id) } {
+            Some(_) => Err(GodotRefError::WrongClass {
+                expected: T::class_name(),
+                actual: self.class_name.to_string(),
+            }),
+            None => Err(GodotRefError::Freed),
+        }
+    }
+
+    /// Returns true if the referenced object hasn't been freed
+    pub fn is_valid(&mut self) -> bool {
+        self.checked_get::<Object>().is_ok()
+    }
+
+    /// The Godot class of the referenced object
+    pub fn class_name(&self) -> &str {
+        &self.class_name
     }
 
     /// # Safety
-    /// When using ErasedGodotRef as a Bevy Resource or Component, do not create duplicate references to the same instance because Godot is not completely thread-safe.
+    /// The object must only be accessed from the main thread through the returned reference, see [`ErasedGodotRef`]
     pub unsafe fn new<T: GodotObject<Memory = ManuallyManaged> + SubClass<Object>, Own: Ownership>(
         reference: Ref<T, Own>,
     ) -> Self
@@ -45,13 +110,64 @@ impl ErasedGodotRef {
 
     /// # Safety
     /// Look to [Self::new]
+    ///
+    /// # Panics
+    /// Panics if the instance id doesn't refer to a live object, see [Self::try_from_instance_id]
     pub unsafe fn from_instance_id(id: i64) -> Self {
-        let obj: TRef<Object> = TRef::from_instance_id(id);
+        Self::try_from_instance_id(id).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// # Safety
+    /// Look to [Self::new]
+    pub unsafe fn try_from_instance_id(id: i64) -> Result<Self, GodotRefError> {
+        let obj: TRef<Object> = TRef::try_from_instance_id(id).ok_or(GodotRefError::Freed)?;
         let object_id = obj.get_instance_id();
-        let class_name = obj.get_class().to_string();
-        Self {
+        let class_name = intern_class_name(obj.get_class().to_string());
+        Ok(Self {
             object_id,
-            class_name,
+            class_name: Cow::Borrowed(class_name),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GodotRefError {
+    /// The object was freed
+    Freed,
+    /// The object isn't an instance of the requested class
+    WrongClass {
+        expected: &'static str,
+        actual: String,
+    },
+}
+
+impl std::fmt::Display for GodotRefError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GodotRefError::Freed => write!(f, "godot object was freed"),
+            GodotRefError::WrongClass { expected, actual } => {
+                write!(
+                    f,
+                    "expected godot object of class {expected}, found {actual}"
+                )
+            }
         }
     }
 }
+
+impl std::error::Error for GodotRefError {}
+
+#[cfg(test)]
+mod tests {
+    use super::intern_class_name;
+
+    #[test]
+    fn class_names_are_interned_once() {
+        let first = intern_class_name("Spatial".to_string());
+        let second = intern_class_name("Spatial".to_string());
+
+        assert_eq!(first, "Spatial");
+        assert!(std::ptr::eq(first, second));
+        assert_ne!(intern_class_name("Node2D".to_string()), first);
+    }
+}
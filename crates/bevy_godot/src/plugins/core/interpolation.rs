@@ -0,0 +1,162 @@
+use crate::{
+    plugins::core::transforms::{post_update_godot_transforms, post_update_godot_transforms_2d},
+    prelude::{godot_prelude::Engine, *},
+};
+
+pub struct GodotTransformInterpolationPlugin;
+
+impl Plugin for GodotTransformInterpolationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(
+            record_interpolated_transforms
+                .as_physics_system()
+                .in_base_set(CoreSet::Last)
+                .after(post_update_godot_transforms)
+                .after(post_update_godot_transforms_2d),
+        )
+        .add_system(
+            interpolate_transforms
+                .as_visual_system()
+                .in_base_set(CoreSet::Last)
+                .after(post_update_godot_transforms)
+                .after(post_update_godot_transforms_2d),
+        );
+    }
+}
+
+/// Bevy Component that smooths the movement of a `Spatial` or `Node2D` moved in the physics frame
+///
+/// The transform of the node is recorded at the end of every physics frame. During visual frames the
+/// `visual_node` child is offset so that it is displayed at a blend of the previous and current
+/// physics transforms, using `Engine::get_physics_interpolation_fraction`. The node itself keeps its
+/// physics transform, so the visual child should hold its meshes or sprites but not its collision shapes.
+#[derive(Component, Debug, Clone)]
+pub struct InterpolatedTransform {
+    /// Path of the child node that is displayed interpolated, relative to the entity's node
+    pub visual_node: String,
+    previous: Option<InterpolatedPose>,
+    current: Option<InterpolatedPose>,
+    /// The transform of the visual node when it was first interpolated
+    rest: Option<InterpolatedPose>,
+}
+
+impl InterpolatedTransform {
+    pub fn new(visual_node: impl ToString) -> Self {
+        Self {
+            visual_node: visual_node.to_string(),
+            previous: None,
+            current: None,
+            rest: None,
+        }
+    }
+
+    /// Forgets the recorded physics transforms, so a teleported node isn't blended from where it was
+    pub fn reset(&mut self) {
+        self.previous = None;
+        self.current = None;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum InterpolatedPose {
+    Spatial(GodotTransform),
+    Node2D(GodotTransform2D),
+}
+
+fn record_interpolated_transforms(
+    _scene_tree: SceneTreeRef,
+    mut entities: Query<(&mut InterpolatedTransform, &mut ErasedGodotRef)>,
+) {
+    for (mut interpolated, mut reference) in entities.iter_mut() {
+        let pose = if let Some(spatial) = reference.try_get::<Spatial>() {
+            InterpolatedPose::Spatial(spatial.transform())
+        } else if let Some(node2d) = reference.try_get::<Node2D>() {
+            InterpolatedPose::Node2D(node2d.get_transform())
+        } else {
+            continue;
+        };
+
+        let interpolated = interpolated.bypass_change_detection();
+        interpolated.previous = interpolated.current.or(Some(pose));
+        interpolated.current = Some(pose);
+    }
+}
+
+fn interpolate_transforms(
+    _scene_tree: SceneTreeRef,
+    mut entities: Query<(&mut InterpolatedTransform, &mut ErasedGodotRef)>,
+) {
+    let fraction = Engine::godot_singleton().get_physics_interpolation_fraction() as f32;
+
+    for (mut interpolated, mut reference) in entities.iter_mut() {
+        let (previous, current) = match (interpolated.previous, interpolated.current) {
+            (Some(previous), Some(current)) => (previous, current),
+            _ => continue,
+        };
+
+        let node = reference.get::<Node>();
+        let visual = match node.get_node_or_null(interpolated.visual_node.as_str()) {
+            Some(visual) => unsafe { visual.assume_safe() },
+            None => {
+                warn!(
+                    "interpolated node {} has no visual child {}",
+                    node.name(),
+                    interpolated.visual_node
+                );
+                continue;
+            }
+        };
+
+        match (previous, current) {
+            (InterpolatedPose::Spatial(previous), InterpolatedPose::Spatial(current)) => {
+                let (spatial, visual) = match (node.cast::<Spatial>(), visual.cast::<Spatial>()) {
+                    (Some(spatial), Some(visual)) => (spatial, visual),
+                    _ => continue,
+                };
+
+                let rest = match interpolated.rest {
+                    Some(InterpolatedPose::Spatial(rest)) => rest,
+                    _ => {
+                        let rest = visual.transform();
+                        interpolated.bypass_change_detection().rest =
+                            Some(InterpolatedPose::Spatial(rest));
+                        rest
+                    }
+                };
+
+                let (previous, current) =
+                    (previous.to_bevy_transform(), current.to_bevy_transform());
+                let blended = BevyTransform {
+                    translation: previous.translation.lerp(current.translation, fraction),
+                    rotation: previous.rotation.slerp(current.rotation, fraction),
+                    scale: previous.scale.lerp(current.scale, fraction),
+                }
+                .to_godot_transform();
+
+                // offset from where the node actually is, which may have moved since it was recorded
+                visual.set_transform(spatial.transform().affine_inverse() * blended * rest);
+            }
+            (InterpolatedPose::Node2D(previous), InterpolatedPose::Node2D(current)) => {
+                let (node2d, visual) = match (node.cast::<Node2D>(), visual.cast::<Node2D>()) {
+                    (Some(node2d), Some(visual)) => (node2d, visual),
+                    _ => continue,
+                };
+
+                let rest = match interpolated.rest {
+                    Some(InterpolatedPose::Node2D(rest)) => rest,
+                    _ => {
+                        let rest = visual.get_transform();
+                        interpolated.bypass_change_detection().rest =
+                            Some(InterpolatedPose::Node2D(rest));
+                        rest
+                    }
+                };
+
+                let blended = previous.interpolate_with(current, fraction);
+
+                visual.set_transform(node2d.get_transform().affine_inverse() * blended * rest);
+            }
+            _ => continue,
+        }
+    }
+}
@@ -0,0 +1,351 @@
+use crate::prelude::{
+    godot_prelude::{KinematicBody, KinematicBody2D, KinematicCollision, KinematicCollision2D},
+    *,
+};
+
+pub struct GodotKinematicPlugin;
+
+impl Plugin for GodotKinematicPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(
+            move_kinematic_bodies
+                .as_physics_system()
+                .in_base_set(CoreSet::PostUpdate),
+        )
+        .add_system(
+            move_kinematic_bodies_2d
+                .as_physics_system()
+                .in_base_set(CoreSet::PostUpdate),
+        );
+    }
+}
+
+/// How a kinematic body is moved by its velocity component
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum KinematicMovement {
+    /// `move_and_slide`, sliding along what it collides with
+    #[default]
+    Slide,
+    /// `move_and_collide`, stopping at the first collision
+    ///
+    /// Godot only detects floors, walls and ceilings in `move_and_slide`, so the body is never on any of them.
+    Collide,
+}
+
+/// Bevy Component that moves a `KinematicBody2D` with `velocity` every physics frame
+///
+/// The body is moved in the PostUpdate stage of the physics frame, after which `velocity` is replaced by the
+/// velocity returned by `move_and_slide`, and the floor state and slide collisions are updated. With
+/// [`KinematicMovement::Collide`] the floor, wall and ceiling state are reset to false. The entity's transform
+/// component is written before and read after moving as allowed by its [`TransformSyncMode`].
+#[derive(Component, Debug, Clone)]
+pub struct KinematicVelocity2D {
+    pub velocity: Vector2,
+    pub movement: KinematicMovement,
+    /// The direction of the ceiling, or zero for top-down games where every collision is a wall
+    pub up_direction: Vector2,
+    pub stop_on_slope: bool,
+    pub max_slides: i64,
+    /// Radians
+    pub floor_max_angle: f64,
+    pub infinite_inertia: bool,
+    on_floor: bool,
+    on_wall: bool,
+    on_ceiling: bool,
+    collisions: Vec<SlideCollision2D>,
+}
+
+impl Default for KinematicVelocity2D {
+    fn default() -> Self {
+        Self {
+            velocity: Vector2::ZERO,
+            movement: KinematicMovement::default(),
+            up_direction: Vector2::ZERO,
+            stop_on_slope: false,
+            max_slides: 4,
+            floor_max_angle: 45f64.to_radians(),
+            infinite_inertia: true,
+            on_floor: false,
+            on_wall: false,
+            on_ceiling: false,
+            collisions: vec![],
+        }
+    }
+}
+
+impl KinematicVelocity2D {
+    pub fn new(velocity: Vector2) -> Self {
+        Self {
+            velocity,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_movement(mut self, movement: KinematicMovement) -> Self {
+        self.movement = movement;
+        self
+    }
+
+    pub fn with_up_direction(mut self, up_direction: Vector2) -> Self {
+        self.up_direction = up_direction;
+        self
+    }
+
+    pub fn is_on_floor(&self) -> bool {
+        self.on_floor
+    }
+
+    pub fn is_on_wall(&self) -> bool {
+        self.on_wall
+    }
+
+    pub fn is_on_ceiling(&self) -> bool {
+        self.on_ceiling
+    }
+
+    /// The collisions of the last movement
+    pub fn collisions(&self) -> &[SlideCollision2D] {
+        &self.collisions
+    }
+}
+
+/// Bevy Component that moves a `KinematicBody` with `velocity` every physics frame, see [`KinematicVelocity2D`]
+#[derive(Component, Debug, Clone)]
+pub struct KinematicVelocity3D {
+    pub velocity: Vector3,
+    pub movement: KinematicMovement,
+    /// The direction of the ceiling, or zero if every collision is a wall
+    pub up_direction: Vector3,
+    pub stop_on_slope: bool,
+    pub max_slides: i64,
+    /// Radians
+    pub floor_max_angle: f64,
+    pub infinite_inertia: bool,
+    on_floor: bool,
+    on_wall: bool,
+    on_ceiling: bool,
+    collisions: Vec<SlideCollision3D>,
+}
+
+impl Default for KinematicVelocity3D {
+    fn default() -> Self {
+        Self {
+            velocity: Vector3::ZERO,
+            movement: KinematicMovement::default(),
+            up_direction: Vector3::ZERO,
+            stop_on_slope: false,
+            max_slides: 4,
+            floor_max_angle: 45f64.to_radians(),
+            infinite_inertia: true,
+            on_floor: false,
+            on_wall: false,
+            on_ceiling: false,
+            collisions: vec![],
+        }
+    }
+}
+
+impl KinematicVelocity3D {
+    pub fn new(velocity: Vector3) -> Self {
+        Self {
+            velocity,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_movement(mut self, movement: KinematicMovement) -> Self {
+        self.movement = movement;
+        self
+    }
+
+    pub fn with_up_direction(mut self, up_direction: Vector3) -> Self {
+        self.up_direction = up_direction;
+        self
+    }
+
+    pub fn is_on_floor(&self) -> bool {
+        self.on_floor
+    }
+
+    pub fn is_on_wall(&self) -> bool {
+        self.on_wall
+    }
+
+    pub fn is_on_ceiling(&self) -> bool {
+        self.on_ceiling
+    }
+
+    /// The collisions of the last movement
+    pub fn collisions(&self) -> &[SlideCollision3D] {
+        &self.collisions
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SlideCollision2D {
+    /// The entity of the collider, if it is mirrored
+    pub entity: Option<Entity>,
+    pub position: Vector2,
+    pub normal: Vector2,
+    pub travel: Vector2,
+    pub remainder: Vector2,
+    pub collider_shape: i64,
+}
+
+impl SlideCollision2D {
+    fn new(collision: TRef<KinematicCollision2D>, entity_map: &GodotEntityMap) -> Self {
+        Self {
+            entity: entity_map.get_entity(collision.collider_id()),
+            position: collision.position(),
+            normal: collision.normal(),
+            travel: collision.travel(),
+            remainder: collision.remainder(),
+            collider_shape: collision.collider_shape_index(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SlideCollision3D {
+    /// The entity of the collider, if it is mirrored
+    pub entity: Option<Entity>,
+    pub position: Vector3,
+    pub normal: Vector3,
+    pub travel: Vector3,
+    pub remainder: Vector3,
+    pub collider_shape: i64,
+}
+
+impl SlideCollision3D {
+    fn new(collision: TRef<KinematicCollision>, entity_map: &GodotEntityMap) -> Self {
+        Self {
+            entity: entity_map.get_entity(collision.collider_id()),
+            position: collision.position(),
+            normal: collision.normal(),
+            travel: collision.travel(),
+            remainder: collision.remainder(),
+            collider_shape: collision.collider_shape_index(),
+        }
+    }
+}
+
+macro_rules! move_kinematic_bodies {
+    (
+        $system:ident,
+        $velocity:ty,
+        $body_class:ty,
+        $transform_type:ty,
+        $collision:ident,
+        |$body:ident, $transform:ident| apply $apply:block read $read:block
+    ) => {
+        fn $system(
+            _scene_tree: SceneTreeRef,
+            entity_map: Res<GodotEntityMap>,
+            mut entities: Query<(
+                &mut $velocity,
+                &mut ErasedGodotRef,
+                Option<&mut $transform_type>,
+                Option<&TransformSyncMode>,
+            )>,
+        ) {
+            for (mut kinematic, mut reference, $transform, sync_mode) in entities.iter_mut() {
+                let sync_mode = sync_mode.copied().unwrap_or_default();
+
+                let $body = match reference.try_get::<$body_class>() {
+                    Some(body) => body,
+                    None => continue,
+                };
+
+                // apply transform changes made this frame before moving
+                if let Some($transform) = $transform
+                    .as_ref()
+                    .filter(|transform| transform.is_changed() && sync_mode.writes_godot())
+                {
+                    $apply
+                }
+
+                let kinematic = &mut *kinematic;
+                kinematic.collisions.clear();
+
+                match kinematic.movement {
+                    KinematicMovement::Slide => {
+                        kinematic.velocity = $body.move_and_slide(
+                            kinematic.velocity,
+                            kinematic.up_direction,
+                            kinematic.stop_on_slope,
+                            kinematic.max_slides,
+                            kinematic.floor_max_angle,
+                            kinematic.infinite_inertia,
+                        );
+
+                        for i in 0..$body.get_slide_count() {
+                            if let Some(collision) = $body.get_slide_collision(i) {
+                                let collision = unsafe { collision.assume_safe() };
+                                kinematic
+                                    .collisions
+                                    .push($collision::new(collision, &entity_map));
+                            }
+                        }
+
+                        kinematic.on_floor = $body.is_on_floor();
+                        kinematic.on_wall = $body.is_on_wall();
+                        kinematic.on_ceiling = $body.is_on_ceiling();
+                    }
+                    KinematicMovement::Collide => {
+                        let motion =
+                            kinematic.velocity * $body.get_physics_process_delta_time() as f32;
+                        if let Some(collision) =
+                            $body.move_and_collide(motion, kinematic.infinite_inertia, true, false)
+                        {
+                            let collision = unsafe { collision.assume_safe() };
+                            kinematic
+                                .collisions
+                                .push($collision::new(collision, &entity_map));
+                        }
+
+                        // godot keeps the state of the last move_and_slide
+                        kinematic.on_floor = false;
+                        kinematic.on_wall = false;
+                        kinematic.on_ceiling = false;
+                    }
+                }
+
+                if let Some(mut $transform) = $transform.filter(|_| sync_mode.reads_godot()) {
+                    $read
+                }
+            }
+        }
+    };
+}
+
+move_kinematic_bodies!(
+    move_kinematic_bodies_2d,
+    KinematicVelocity2D,
+    KinematicBody2D,
+    Transform2D,
+    SlideCollision2D,
+    |body, transform| apply {
+        body.set_transform(transform.as_godot());
+    } read {
+        let body_transform = body.get_transform();
+        if !transform.is_equal_approx_godot(body_transform) {
+            *transform = body_transform.to_bevy_transform_2d();
+        }
+    }
+);
+
+move_kinematic_bodies!(
+    move_kinematic_bodies,
+    KinematicVelocity3D,
+    KinematicBody,
+    Transform,
+    SlideCollision3D,
+    |body, transform| apply {
+        body.set_transform(*transform.as_godot());
+    } read {
+        let body_transform = body.transform();
+        if *transform.as_godot() != body_transform {
+            *transform.as_godot_mut() = body_transform;
+        }
+    }
+);
@@ -9,18 +9,57 @@ pub use godot_ref::*;
 pub mod transforms;
 pub use transforms::{Transform, Transform2D, *};
 
+pub mod interpolation;
+pub use interpolation::*;
+
+pub mod control;
+pub use control::*;
+
 pub mod scene_tree;
 pub use scene_tree::*;
 
+pub mod scene_tree_config;
+pub use scene_tree_config::*;
+
+pub mod entity_map;
+pub use entity_map::*;
+
+pub mod typed_node;
+pub use typed_node::*;
+
 pub mod collisions;
 pub use collisions::*;
 
+pub mod collision_layers;
+pub use collision_layers::*;
+
+pub mod kinematic;
+pub use kinematic::*;
+
+pub mod rigid_body;
+pub use rigid_body::*;
+
+pub mod physics_query;
+pub use physics_query::*;
+
 pub mod signals;
 pub use signals::*;
 
+pub mod signal_connections;
+pub use signal_connections::*;
+
+pub mod godot_calls;
+pub use godot_calls::*;
+
+pub mod user_signals;
+pub use user_signals::*;
+
 pub mod input_event;
 pub use input_event::*;
 
+pub mod properties;
+pub use properties::*;
+
 pub struct GodotCorePlugin;
 
 impl Plugin for GodotCorePlugin {
@@ -34,8 +73,15 @@ impl Plugin for GodotCorePlugin {
             .add_plugin(bevy::hierarchy::HierarchyPlugin)
             .add_plugin(GodotSceneTreePlugin)
             .add_plugin(GodotTransformsPlugin)
+            .add_plugin(GodotTransformInterpolationPlugin)
+            .add_plugin(GodotControlPlugin)
             .add_plugin(GodotCollisionsPlugin)
+            .add_plugin(GodotCollisionLayersPlugin)
+            .add_plugin(GodotKinematicPlugin)
+            .add_plugin(GodotRigidBodyPlugin)
             .add_plugin(GodotSignalsPlugin)
+            .add_plugin(GodotSignalConnectionsPlugin)
+            .add_plugin(GodotCallsPlugin)
             .add_plugin(GodotInputEventPlugin);
     }
 }
@@ -0,0 +1,482 @@
+use crate::prelude::{
+    godot_prelude::{
+        Dictionary, Physics2DDirectSpaceState, Physics2DShapeQueryParameters,
+        PhysicsDirectSpaceState, PhysicsShapeQueryParameters, Shape, Shape2D, Unique, VariantArray,
+    },
+    *,
+};
+use bevy::ecs::system::SystemParam;
+
+/// Bevy SystemParam to query the physics space of the scene tree's root viewport
+///
+/// Results are resolved to the entities mirroring the colliders. Ray and motion casts stop at the first collider
+/// like in Godot, see [`PhysicsQueryFilter::pass_through_filtered`] to cast past colliders that aren't reported.
+/// Godot only allows accessing the physics space during the physics frame, so systems using it should be
+/// scheduled with `as_physics_system`.
+#[derive(SystemParam)]
+pub struct GodotPhysicsQuery<'w, 's> {
+    scene_tree: SceneTreeRef<'w, 's>,
+    entity_map: Res<'w, GodotEntityMap>,
+}
+
+/// Filters the colliders reported by a [`GodotPhysicsQuery`]
+#[derive(Debug, Clone)]
+pub struct PhysicsQueryFilter {
+    pub collision_mask: u32,
+    pub collide_with_bodies: bool,
+    pub collide_with_areas: bool,
+    pub exclude: Vec<Entity>,
+    /// Only report colliders in this group
+    pub group: Option<String>,
+    /// Let ray and motion casts pass through colliders that aren't mirrored or aren't in `group`, instead of
+    /// stopping at them
+    pub pass_through_filtered: bool,
+}
+
+impl Default for PhysicsQueryFilter {
+    fn default() -> Self {
+        Self {
+            collision_mask: 0x7FFF_FFFF,
+            collide_with_bodies: true,
+            collide_with_areas: false,
+            exclude: vec![],
+            group: None,
+            pass_through_filtered: false,
+        }
+    }
+}
+
+impl PhysicsQueryFilter {
+    pub fn with_collision_mask(mut self, collision_mask: u32) -> Self {
+        self.collision_mask = collision_mask;
+        self
+    }
+
+    pub fn with_bodies(mut self, collide_with_bodies: bool) -> Self {
+        self.collide_with_bodies = collide_with_bodies;
+        self
+    }
+
+    pub fn with_areas(mut self, collide_with_areas: bool) -> Self {
+        self.collide_with_areas = collide_with_areas;
+        self
+    }
+
+    pub fn excluding(mut self, entity: Entity) -> Self {
+        self.exclude.push(entity);
+        self
+    }
+
+    pub fn in_group(mut self, group: impl ToString) -> Self {
+        self.group = Some(group.to_string());
+        self
+    }
+
+    pub fn with_pass_through_filtered(mut self, pass_through_filtered: bool) -> Self {
+        self.pass_through_filtered = pass_through_filtered;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit2D {
+    /// The entity of the collider, `None` if the collider isn't mirrored
+    pub entity: Option<Entity>,
+    pub position: Vector2,
+    pub normal: Vector2,
+    /// The index of the collider's shape that was hit
+    pub shape: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit3D {
+    /// The entity of the collider, `None` if the collider isn't mirrored
+    pub entity: Option<Entity>,
+    pub position: Vector3,
+    pub normal: Vector3,
+    /// The index of the collider's shape that was hit
+    pub shape: i64,
+}
+
+/// A collider intersecting a point or shape
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShapeHit {
+    pub entity: Entity,
+    /// The index of the collider's shape that intersects
+    pub shape: i64,
+}
+
+/// The fractions of a shape's motion that are free of collisions and that collide
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MotionCast {
+    pub safe_fraction: f32,
+    pub unsafe_fraction: f32,
+    /// The collider stopping the motion, `None` if the motion is free or the collider isn't mirrored
+    pub entity: Option<Entity>,
+}
+
+impl<'w, 's> GodotPhysicsQuery<'w, 's> {
+    pub fn cast_ray_2d(
+        &mut self,
+        from: Vector2,
+        to: Vector2,
+        filter: &PhysicsQueryFilter,
+    ) -> Option<RayHit2D> {
+        let space = self.space_2d()?;
+        let exclude = self.exclude(filter);
+
+        loop {
+            let hit = space.intersect_ray(
+                from,
+                to,
+                exclude.duplicate().into_shared(),
+                filter.collision_mask as i64,
+                filter.collide_with_bodies,
+                filter.collide_with_areas,
+            );
+
+            let (collider, entity) = self.resolve_collider(&hit)?;
+            if self.is_reported(collider, entity, filter) {
+                return Some(RayHit2D {
+                    entity,
+                    position: hit.get_or_nil("position").try_to().ok()?,
+                    normal: hit.get_or_nil("normal").try_to().ok()?,
+                    shape: hit.get_or_nil("shape").try_to().unwrap_or_default(),
+                });
+            }
+
+            if !filter.pass_through_filtered {
+                return None;
+            }
+
+            exclude.push(collider);
+        }
+    }
+
+    pub fn cast_ray_3d(
+        &mut self,
+        from: Vector3,
+        to: Vector3,
+        filter: &PhysicsQueryFilter,
+    ) -> Option<RayHit3D> {
+        let space = self.space_3d()?;
+        let exclude = self.exclude(filter);
+
+        loop {
+            let hit = space.intersect_ray(
+                from,
+                to,
+                exclude.duplicate().into_shared(),
+                filter.collision_mask as i64,
+                filter.collide_with_bodies,
+                filter.collide_with_areas,
+            );
+
+            let (collider, entity) = self.resolve_collider(&hit)?;
+            if self.is_reported(collider, entity, filter) {
+                return Some(RayHit3D {
+                    entity,
+                    position: hit.get_or_nil("position").try_to().ok()?,
+                    normal: hit.get_or_nil("normal").try_to().ok()?,
+                    shape: hit.get_or_nil("shape").try_to().unwrap_or_default(),
+                });
+            }
+
+            if !filter.pass_through_filtered {
+                return None;
+            }
+
+            exclude.push(collider);
+        }
+    }
+
+    /// Returns up to `max_results` colliders containing the point, before filtering by group
+    pub fn intersect_point_2d(
+        &mut self,
+        point: Vector2,
+        max_results: usize,
+        filter: &PhysicsQueryFilter,
+    ) -> Vec<ShapeHit> {
+        let space = match self.space_2d() {
+            Some(space) => space,
+            None => return vec![],
+        };
+
+        let hits = space.intersect_point(
+            point,
+            max_results as i64,
+            self.exclude(filter).into_shared(),
+            filter.collision_mask as i64,
+            filter.collide_with_bodies,
+            filter.collide_with_areas,
+        );
+        self.resolve_shape_hits(hits, filter)
+    }
+
+    /// Returns up to `max_results` colliders containing the point, before filtering by group
+    pub fn intersect_point_3d(
+        &mut self,
+        point: Vector3,
+        max_results: usize,
+        filter: &PhysicsQueryFilter,
+    ) -> Vec<ShapeHit> {
+        let space = match self.space_3d() {
+            Some(space) => space,
+            None => return vec![],
+        };
+
+        let hits = space.intersect_point(
+            point,
+            max_results as i64,
+            self.exclude(filter).into_shared(),
+            filter.collision_mask as i64,
+            filter.collide_with_bodies,
+            filter.collide_with_areas,
+        );
+        self.resolve_shape_hits(hits, filter)
+    }
+
+    /// Returns up to `max_results` colliders intersecting the shape, before filtering by group
+    pub fn intersect_shape_2d(
+        &mut self,
+        shape: &Ref<Shape2D>,
+        transform: GodotTransform2D,
+        max_results: usize,
+        filter: &PhysicsQueryFilter,
+    ) -> Vec<ShapeHit> {
+        let space = match self.space_2d() {
+            Some(space) => space,
+            None => return vec![],
+        };
+
+        let parameters = self.shape_parameters_2d(
+            shape,
+            transform,
+            Vector2::ZERO,
+            &self.exclude(filter),
+            filter,
+        );
+        let hits = space.intersect_shape(parameters, max_results as i64);
+        self.resolve_shape_hits(hits, filter)
+    }
+
+    /// Returns up to `max_results` colliders intersecting the shape, before filtering by group
+    pub fn intersect_shape_3d(
+        &mut self,
+        shape: &Ref<Shape>,
+        transform: GodotTransform,
+        max_results: usize,
+        filter: &PhysicsQueryFilter,
+    ) -> Vec<ShapeHit> {
+        let space = match self.space_3d() {
+            Some(space) => space,
+            None => return vec![],
+        };
+
+        let parameters = self.shape_parameters_3d(shape, transform, &self.exclude(filter), filter);
+        let hits = space.intersect_shape(parameters, max_results as i64);
+        self.resolve_shape_hits(hits, filter)
+    }
+
+    /// Casts the shape along `motion`, returns `None` if the motion is stopped by a collider that isn't reported
+    pub fn cast_motion_2d(
+        &mut self,
+        shape: &Ref<Shape2D>,
+        transform: GodotTransform2D,
+        motion: Vector2,
+        filter: &PhysicsQueryFilter,
+    ) -> Option<MotionCast> {
+        let space = self.space_2d()?;
+        let exclude = self.exclude(filter);
+
+        loop {
+            let parameters = self.shape_parameters_2d(shape, transform, motion, &exclude, filter);
+            let mut cast = motion_cast(space.cast_motion(parameters))?;
+            if cast.unsafe_fraction >= 1.0 {
+                return Some(cast);
+            }
+
+            // the collider isn't part of the cast's result, so it's looked up at the point of contact
+            let mut contact = transform;
+            contact.origin += motion * cast.unsafe_fraction;
+            let parameters =
+                self.shape_parameters_2d(shape, contact, Vector2::ZERO, &exclude, filter);
+            let (collider, entity) = match self.resolve_collider(&space.get_rest_info(parameters)) {
+                Some(collider) => collider,
+                None => return Some(cast),
+            };
+
+            if self.is_reported(collider, entity, filter) {
+                cast.entity = entity;
+                return Some(cast);
+            }
+
+            if !filter.pass_through_filtered {
+                return None;
+            }
+
+            exclude.push(collider);
+        }
+    }
+
+    /// Casts the shape along `motion`, returns `None` if the motion is stopped by a collider that isn't reported
+    pub fn cast_motion_3d(
+        &mut self,
+        shape: &Ref<Shape>,
+        transform: GodotTransform,
+        motion: Vector3,
+        filter: &PhysicsQueryFilter,
+    ) -> Option<MotionCast> {
+        let space = self.space_3d()?;
+        let exclude = self.exclude(filter);
+
+        loop {
+            let parameters = self.shape_parameters_3d(shape, transform, &exclude, filter);
+            let mut cast = motion_cast(space.cast_motion(parameters, motion))?;
+            if cast.unsafe_fraction >= 1.0 {
+                return Some(cast);
+            }
+
+            // the collider isn't part of the cast's result, so it's looked up at the point of contact
+            let mut contact = transform;
+            contact.origin += motion * cast.unsafe_fraction;
+            let parameters = self.shape_parameters_3d(shape, contact, &exclude, filter);
+            let (collider, entity) = match self.resolve_collider(&space.get_rest_info(parameters)) {
+                Some(collider) => collider,
+                None => return Some(cast),
+            };
+
+            if self.is_reported(collider, entity, filter) {
+                cast.entity = entity;
+                return Some(cast);
+            }
+
+            if !filter.pass_through_filtered {
+                return None;
+            }
+
+            exclude.push(collider);
+        }
+    }
+
+    fn space_2d<'a>(&mut self) -> Option<TRef<'a, Physics2DDirectSpaceState>> {
+        let world = self.scene_tree.get_root().find_world_2d()?;
+        let space = unsafe { world.assume_safe() }.direct_space_state()?;
+        Some(unsafe { space.assume_safe() })
+    }
+
+    fn space_3d<'a>(&mut self) -> Option<TRef<'a, PhysicsDirectSpaceState>> {
+        let world = self.scene_tree.get_root().find_world()?;
+        let space = unsafe { world.assume_safe() }.direct_space_state()?;
+        Some(unsafe { space.assume_safe() })
+    }
+
+    fn shape_parameters_2d(
+        &self,
+        shape: &Ref<Shape2D>,
+        transform: GodotTransform2D,
+        motion: Vector2,
+        exclude: &VariantArray<Unique>,
+        filter: &PhysicsQueryFilter,
+    ) -> Ref<Physics2DShapeQueryParameters> {
+        let parameters = Physics2DShapeQueryParameters::new();
+        parameters.set_shape(unsafe { shape.assume_safe() });
+        parameters.set_transform(transform);
+        parameters.set_motion(motion);
+        parameters.set_exclude(exclude.duplicate().into_shared());
+        parameters.set_collision_layer(filter.collision_mask as i64);
+        parameters.set_collide_with_bodies(filter.collide_with_bodies);
+        parameters.set_collide_with_areas(filter.collide_with_areas);
+        parameters.into_shared()
+    }
+
+    fn shape_parameters_3d(
+        &self,
+        shape: &Ref<Shape>,
+        transform: GodotTransform,
+        exclude: &VariantArray<Unique>,
+        filter: &PhysicsQueryFilter,
+    ) -> Ref<PhysicsShapeQueryParameters> {
+        let parameters = PhysicsShapeQueryParameters::new();
+        parameters.set_shape(unsafe { shape.assume_safe() });
+        parameters.set_transform(transform);
+        parameters.set_exclude(exclude.duplicate().into_shared());
+        parameters.set_collision_mask(filter.collision_mask as i64);
+        parameters.set_collide_with_bodies(filter.collide_with_bodies);
+        parameters.set_collide_with_areas(filter.collide_with_areas);
+        parameters.into_shared()
+    }
+
+    /// The nodes of the excluded entities, which Godot converts to their RIDs
+    fn exclude(&self, filter: &PhysicsQueryFilter) -> VariantArray<Unique> {
+        let exclude = VariantArray::new();
+        for instance_id in filter
+            .exclude
+            .iter()
+            .filter_map(|entity| self.entity_map.get_instance_id(*entity))
+        {
+            if let Some(node) = unsafe { TRef::<Object>::try_from_instance_id(instance_id) } {
+                exclude.push(node);
+            }
+        }
+        exclude
+    }
+
+    /// Returns the collider of the query result and its entity, if it is mirrored
+    fn resolve_collider<'a>(&self, hit: &Dictionary) -> Option<(TRef<'a, Object>, Option<Entity>)> {
+        let instance_id = hit.get("collider_id")?.try_to::<i64>().ok()?;
+        let collider = unsafe { TRef::<Object>::try_from_instance_id(instance_id) }?;
+
+        Some((collider, self.entity_map.get_entity(instance_id)))
+    }
+
+    /// Returns true if casts report the collider, otherwise they pass through it or stop without a result
+    fn is_reported(
+        &self,
+        collider: TRef<Object>,
+        entity: Option<Entity>,
+        filter: &PhysicsQueryFilter,
+    ) -> bool {
+        if filter.pass_through_filtered && entity.is_none() {
+            return false;
+        }
+
+        self.matches_group(collider, filter)
+    }
+
+    fn matches_group(&self, collider: TRef<Object>, filter: &PhysicsQueryFilter) -> bool {
+        match &filter.group {
+            Some(group) => collider
+                .cast::<Node>()
+                .map(|node| node.is_in_group(group.as_str()))
+                .unwrap_or(false),
+            None => true,
+        }
+    }
+
+    fn resolve_shape_hits(&self, hits: VariantArray, filter: &PhysicsQueryFilter) -> Vec<ShapeHit> {
+        hits.iter()
+            .filter_map(|hit| hit.try_to::<Dictionary>().ok())
+            .filter_map(|hit| {
+                let (collider, entity) = self.resolve_collider(&hit)?;
+                let entity = entity?;
+                self.matches_group(collider, filter).then(|| ShapeHit {
+                    entity,
+                    shape: hit.get_or_nil("shape").try_to().unwrap_or_default(),
+                })
+            })
+            .collect()
+    }
+}
+
+fn motion_cast(fractions: VariantArray) -> Option<MotionCast> {
+    if fractions.len() != 2 {
+        return None;
+    }
+
+    Some(MotionCast {
+        safe_fraction: fractions.get(0).try_to().ok()?,
+        unsafe_fraction: fractions.get(1).try_to().ok()?,
+        entity: None,
+    })
+}
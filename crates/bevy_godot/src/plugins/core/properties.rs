@@ -0,0 +1,61 @@
+use crate::prelude::*;
+use std::marker::PhantomData;
+
+/// A component whose fields mirror properties of the entity's Godot node
+///
+/// Implement it with `#[derive(GodotProperties)]`. Fields mirror the property of the same name unless renamed with
+/// `#[godot(property = "..")]` or skipped with `#[godot(skip)]`, and must implement `ToVariant`, `FromVariant` and `PartialEq`.
+pub trait GodotProperties: Component + Sized {
+    /// Updates the fields from the node's properties, only mutating fields whose values differ
+    fn read_properties(component: &mut Mut<Self>, node: TRef<Object>);
+
+    /// Writes the fields to the node's properties
+    fn write_properties(&self, node: TRef<Object>);
+}
+
+/// Synchronizes the [`GodotProperties`] component `T` with the entity's node
+///
+/// Properties are read in the PreUpdate stage and written back in the Last stage when the component changed.
+pub struct GodotPropertiesPlugin<T>(PhantomData<fn() -> T>);
+
+impl<T> Default for GodotPropertiesPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: GodotProperties> Plugin for GodotPropertiesPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_system(post_update_godot_properties::<T>.in_base_set(CoreSet::Last))
+            .add_system(pre_update_godot_properties::<T>.in_base_set(CoreSet::PreUpdate));
+    }
+}
+
+fn post_update_godot_properties<T: GodotProperties>(
+    _scene_tree: SceneTreeRef,
+    mut entities: Query<(&T, &mut ErasedGodotRef), Changed<T>>,
+) {
+    for (component, mut reference) in entities.iter_mut() {
+        // the node may have been freed since the start of the update
+        let node = match reference.try_get::<Object>() {
+            Some(node) => node,
+            None => continue,
+        };
+
+        component.write_properties(node);
+    }
+}
+
+fn pre_update_godot_properties<T: GodotProperties>(
+    _scene_tree: SceneTreeRef,
+    mut entities: Query<(&mut T, &mut ErasedGodotRef)>,
+) {
+    for (mut component, mut reference) in entities.iter_mut() {
+        let node = match reference.try_get::<Object>() {
+            Some(node) => node,
+            None => continue,
+        };
+
+        T::read_properties(&mut component, node);
+    }
+}
@@ -0,0 +1,302 @@
+use crate::prelude::{
+    godot_prelude::{RigidBody, RigidBody2D},
+    *,
+};
+use std::collections::HashMap;
+
+pub struct GodotRigidBodyPlugin;
+
+impl Plugin for GodotRigidBodyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(
+            pre_update_rigid_bodies
+                .as_physics_system()
+                .in_base_set(CoreSet::PreUpdate),
+        )
+        .add_system(
+            pre_update_rigid_bodies_2d
+                .as_physics_system()
+                .in_base_set(CoreSet::PreUpdate),
+        )
+        .add_system(
+            post_update_rigid_bodies
+                .as_physics_system()
+                .in_base_set(CoreSet::Last),
+        )
+        .add_system(
+            post_update_rigid_bodies_2d
+                .as_physics_system()
+                .in_base_set(CoreSet::Last),
+        );
+    }
+}
+
+/// Bevy Component mirroring the linear velocity of a `RigidBody`
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct LinearVelocity(pub Vec3);
+
+/// Bevy Component mirroring the angular velocity of a `RigidBody`
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct AngularVelocity(pub Vec3);
+
+/// Bevy Component mirroring the linear velocity of a `RigidBody2D`
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct LinearVelocity2D(pub Vec2);
+
+/// Bevy Component mirroring the angular velocity of a `RigidBody2D`, in radians per second
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct AngularVelocity2D(pub f32);
+
+/// Bevy Component with the constant force and torque applied to a `RigidBody`
+///
+/// Only the difference to the previously applied force is added to the body, so forces added to it elsewhere,
+/// e.g. from GDScript, are kept. Removing the component or despawning the entity stops applying the force and torque.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct ExternalForce {
+    pub force: Vec3,
+    pub torque: Vec3,
+}
+
+impl ExternalForce {
+    pub fn new(force: Vec3, torque: Vec3) -> Self {
+        Self { force, torque }
+    }
+}
+
+/// Bevy Component with the constant force and torque applied to a `RigidBody2D`, see [`ExternalForce`]
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct ExternalForce2D {
+    pub force: Vec2,
+    pub torque: f32,
+}
+
+impl ExternalForce2D {
+    pub fn new(force: Vec2, torque: f32) -> Self {
+        Self { force, torque }
+    }
+}
+
+/// Bevy Component with an impulse applied once to a `RigidBody` in the next physics frame
+///
+/// The impulse is reset to zero after being applied.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct ExternalImpulse {
+    pub impulse: Vec3,
+    pub torque_impulse: Vec3,
+}
+
+/// Bevy Component with an impulse applied once to a `RigidBody2D` in the next physics frame, see [`ExternalImpulse`]
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct ExternalImpulse2D {
+    pub impulse: Vec2,
+    pub torque_impulse: f32,
+}
+
+/// Conversion between the Bevy values of the components and the Godot values of the bodies
+trait BodyValue: Copy + PartialEq {
+    type Godot;
+
+    fn from_godot(value: Self::Godot) -> Self;
+
+    fn to_godot(self) -> Self::Godot;
+
+    fn is_zero(self) -> bool;
+}
+
+impl BodyValue for Vec3 {
+    type Godot = Vector3;
+
+    fn from_godot(value: Vector3) -> Self {
+        Vec3::new(value.x, value.y, value.z)
+    }
+
+    fn to_godot(self) -> Vector3 {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    fn is_zero(self) -> bool {
+        self == Vec3::ZERO
+    }
+}
+
+impl BodyValue for Vec2 {
+    type Godot = Vector2;
+
+    fn from_godot(value: Vector2) -> Self {
+        Vec2::new(value.x, value.y)
+    }
+
+    fn to_godot(self) -> Vector2 {
+        Vector2::new(self.x, self.y)
+    }
+
+    fn is_zero(self) -> bool {
+        self == Vec2::ZERO
+    }
+}
+
+impl BodyValue for f32 {
+    type Godot = f64;
+
+    fn from_godot(value: f64) -> Self {
+        value as f32
+    }
+
+    fn to_godot(self) -> f64 {
+        self as f64
+    }
+
+    fn is_zero(self) -> bool {
+        self == 0.0
+    }
+}
+
+macro_rules! rigid_body_systems {
+    (
+        $pre_update:ident,
+        $post_update:ident,
+        $body_class:ty,
+        $linear_velocity:ident,
+        $angular_velocity:ident,
+        $external_force:ident,
+        $external_impulse:ident
+    ) => {
+        fn $pre_update(
+            _scene_tree: SceneTreeRef,
+            mut entities: Query<
+                (
+                    &mut ErasedGodotRef,
+                    Option<&mut $linear_velocity>,
+                    Option<&mut $angular_velocity>,
+                ),
+                Or<(With<$linear_velocity>, With<$angular_velocity>)>,
+            >,
+        ) {
+            for (mut reference, linear_velocity, angular_velocity) in entities.iter_mut() {
+                let body = match reference.try_get::<$body_class>() {
+                    Some(body) => body,
+                    None => continue,
+                };
+
+                if let Some(mut linear_velocity) = linear_velocity {
+                    let linear = BodyValue::from_godot(body.linear_velocity());
+                    if linear_velocity.0 != linear {
+                        linear_velocity.0 = linear;
+                    }
+                }
+
+                if let Some(mut angular_velocity) = angular_velocity {
+                    let angular = BodyValue::from_godot(body.angular_velocity());
+                    if angular_velocity.0 != angular {
+                        angular_velocity.0 = angular;
+                    }
+                }
+            }
+        }
+
+        fn $post_update(
+            _scene_tree: SceneTreeRef,
+            mut entities: Query<
+                (
+                    Entity,
+                    &mut ErasedGodotRef,
+                    Option<&mut $linear_velocity>,
+                    Option<&mut $angular_velocity>,
+                    Option<&mut $external_force>,
+                    Option<&mut $external_impulse>,
+                ),
+                Or<(
+                    Changed<$linear_velocity>,
+                    Changed<$angular_velocity>,
+                    Changed<$external_force>,
+                    Changed<$external_impulse>,
+                )>,
+            >,
+            mut removed_forces: RemovedComponents<$external_force>,
+            // the force last applied to the body of each entity, kept outside the component so that it
+            // survives the component being removed or re-inserted
+            mut applied_forces: Local<HashMap<Entity, (i64, $external_force)>>,
+        ) {
+            for ent in removed_forces.iter() {
+                let (instance_id, applied) = match applied_forces.remove(&ent) {
+                    Some(applied) => applied,
+                    None => continue,
+                };
+
+                // the body may have been freed together with the entity
+                if let Some(body) =
+                    unsafe { TRef::<$body_class>::try_from_instance_id(instance_id) }
+                {
+                    body.add_central_force((-applied.force).to_godot());
+                    body.add_torque((-applied.torque).to_godot());
+                }
+            }
+
+            for (ent, mut reference, linear_velocity, angular_velocity, force, impulse) in
+                entities.iter_mut()
+            {
+                let instance_id = reference.instance_id();
+                let body = match reference.try_get::<$body_class>() {
+                    Some(body) => body,
+                    None => continue,
+                };
+
+                if let Some(linear_velocity) = linear_velocity.filter(|v| v.is_changed()) {
+                    let linear = linear_velocity.0.to_godot();
+                    if body.linear_velocity() != linear {
+                        body.set_linear_velocity(linear);
+                    }
+                }
+
+                if let Some(angular_velocity) = angular_velocity.filter(|v| v.is_changed()) {
+                    let angular = angular_velocity.0.to_godot();
+                    if body.angular_velocity() != angular {
+                        body.set_angular_velocity(angular);
+                    }
+                }
+
+                if let Some(force) = force.filter(|force| force.is_changed()) {
+                    let applied = match applied_forces.get(&ent) {
+                        Some((applied_id, applied)) if *applied_id == instance_id => *applied,
+                        _ => $external_force::default(),
+                    };
+
+                    // the body accumulates forces, so only the difference is added
+                    body.add_central_force((force.force - applied.force).to_godot());
+                    body.add_torque((force.torque - applied.torque).to_godot());
+
+                    applied_forces.insert(ent, (instance_id, *force));
+                }
+
+                if let Some(mut impulse) = impulse.filter(|impulse| {
+                    !impulse.impulse.is_zero() || !impulse.torque_impulse.is_zero()
+                }) {
+                    body.apply_central_impulse(impulse.impulse.to_godot());
+                    body.apply_torque_impulse(impulse.torque_impulse.to_godot());
+
+                    *impulse = $external_impulse::default();
+                }
+            }
+        }
+    };
+}
+
+rigid_body_systems!(
+    pre_update_rigid_bodies,
+    post_update_rigid_bodies,
+    RigidBody,
+    LinearVelocity,
+    AngularVelocity,
+    ExternalForce,
+    ExternalImpulse
+);
+
+rigid_body_systems!(
+    pre_update_rigid_bodies_2d,
+    post_update_rigid_bodies_2d,
+    RigidBody2D,
+    LinearVelocity2D,
+    AngularVelocity2D,
+    ExternalForce2D,
+    ExternalImpulse2D
+);
@@ -1,9 +1,12 @@
-use crate::prelude::{
-    godot_prelude::{Engine, FromVariant, SubClass, ToVariant, VariantArray, Viewport},
-    *,
+use crate::{
+    init_macro::transform_watcher::TRANSFORM_WATCHER_META,
+    prelude::{
+        godot_prelude::{Engine, FromVariant, SubClass, ToVariant, VariantArray, Viewport},
+        *,
+    },
 };
-use bevy::ecs::system::SystemParam;
-use std::{collections::HashMap, marker::PhantomData};
+use bevy::ecs::system::{Command, SystemParam};
+use std::marker::PhantomData;
 
 pub struct GodotSceneTreePlugin;
 
@@ -16,12 +19,36 @@ impl Plugin for GodotSceneTreePlugin {
                     .in_base_set(CoreSet::First)
                     .before(Events::<SceneTreeEvent>::update_system),
             )
+            .add_system(
+                clear_removed_godot_refs
+                    .in_base_set(CoreSet::First)
+                    .before(index_added_godot_refs)
+                    .before(unindex_removed_godot_refs),
+            )
+            .add_system(
+                index_added_godot_refs
+                    .in_base_set(CoreSet::First)
+                    .before(read_scene_tree_events),
+            )
+            .add_system(
+                unindex_removed_godot_refs
+                    .in_base_set(CoreSet::First)
+                    .before(read_scene_tree_events),
+            )
             .add_system(
                 read_scene_tree_events
                     .in_base_set(CoreSet::First)
                     .after(Events::<SceneTreeEvent>::update_system),
             )
+            .add_system(
+                despawn_freed_detached_nodes
+                    .in_base_set(CoreSet::First)
+                    .after(read_scene_tree_events),
+            )
             .add_event::<SceneTreeEvent>()
+            .add_event::<NodeReparented>()
+            .init_resource::<GodotEntityMap>()
+            .init_resource::<SceneTreeConfig>()
             .init_non_send_resource::<SceneTreeRefImpl>();
     }
 }
@@ -79,7 +106,10 @@ impl Default for SceneTreeRefImpl {
 fn initialize_scene_tree(
     mut commands: Commands,
     mut scene_tree: SceneTreeRef,
-    mut entities: Query<(&mut ErasedGodotRef, Entity)>,
+    mut entity_map: ResMut<GodotEntityMap>,
+    config: Res<SceneTreeConfig>,
+    hierarchy: Query<(Option<&Parent>, Option<&Detached>)>,
+    mut reparented: EventWriter<NodeReparented>,
 ) {
     fn traverse(node: TRef<Node>, events: &mut Vec<SceneTreeEvent>) {
         unsafe {
@@ -103,7 +133,15 @@ fn initialize_scene_tree(
         let mut events = vec![];
         traverse(root.upcast(), &mut events);
 
-        create_scene_tree_entity(&mut commands, events, &mut scene_tree, &mut entities);
+        create_scene_tree_entity(
+            &mut commands,
+            events,
+            &mut scene_tree,
+            &mut entity_map,
+            &config,
+            &hierarchy,
+            &mut reparented,
+        );
     }
 }
 
@@ -120,6 +158,23 @@ pub enum SceneTreeEventType {
     NodeRenamed,
 }
 
+/// Sent when a mirrored node is moved to a different parent in the Godot scene tree
+///
+/// The entity's [`Parent`] is updated to the entity of the closest mirrored ancestor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeReparented {
+    pub entity: Entity,
+    pub old_parent: Option<Entity>,
+    pub new_parent: Option<Entity>,
+}
+
+/// Marker component for entities whose node was removed from the scene tree without being freed
+///
+/// Only inserted when [`SceneTreeConfig::keep_detached_nodes`] is enabled and removed again once the
+/// node is added back to the scene tree. The entity is despawned once the detached node is freed.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct Detached;
+
 fn connect_scene_tree(mut scene_tree: SceneTreeRef) {
     let scene_tree = scene_tree.get();
     let watcher = unsafe {
@@ -207,12 +262,11 @@ fn create_scene_tree_entity(
     commands: &mut Commands,
     events: impl IntoIterator<Item = SceneTreeEvent>,
     scene_tree: &mut SceneTreeRef,
-    entities: &mut Query<(&mut ErasedGodotRef, Entity)>,
+    entity_map: &mut GodotEntityMap,
+    config: &SceneTreeConfig,
+    hierarchy: &Query<(Option<&Parent>, Option<&Detached>)>,
+    reparented: &mut EventWriter<NodeReparented>,
 ) {
-    let mut ent_mapping = entities
-        .iter()
-        .map(|(reference, ent)| (reference.instance_id(), ent))
-        .collect::<HashMap<_, _>>();
     let scene_root = unsafe { scene_tree.get().root().unwrap().assume_safe() };
     let collision_watcher = unsafe {
         scene_root
@@ -221,103 +275,261 @@ fn create_scene_tree_entity(
             .assume_safe()
     };
 
+    let control_watcher = unsafe {
+        scene_root
+            .get_node("/root/Autoload/ControlWatcher")
+            .unwrap()
+            .assume_safe()
+    };
+
     for event in events.into_iter() {
         trace!(target: "godot_scene_tree_events", event = ?event);
 
         let mut node = event.node.clone();
-        let ent = ent_mapping.get(&node.instance_id()).cloned();
+        let ent = entity_map.get_entity(node.instance_id());
 
         match event.event_type {
             SceneTreeEventType::NodeAdded => {
+                if ent.is_none() && !config.should_mirror(node.get::<Node>()) {
+                    continue;
+                }
+
+                // watchers added by the transforms plugin are an implementation detail
+                if node.get::<Node>().has_meta(TRANSFORM_WATCHER_META) {
+                    continue;
+                }
+
+                let (old_parent, was_detached) = ent
+                    .and_then(|ent| hierarchy.get(ent).ok())
+                    .map(|(parent, detached)| (parent.map(Parent::get), detached.is_some()))
+                    .unwrap_or_default();
+
                 let mut ent = if let Some(ent) = ent {
                     commands.entity(ent)
                 } else {
                     commands.spawn_empty()
                 };
 
+                if was_detached {
+                    ent.remove::<Detached>();
+                }
+
                 ent.insert(ErasedGodotRef::clone(&node))
                     .insert(Name::from(node.get::<Node>().name().to_string()));
 
+                insert_node_class_components(&mut ent, &mut node);
+
                 if let Some(spatial) = node.try_get::<Spatial>() {
-                    ent.insert(Transform::from(spatial.transform().to_bevy_transform()));
+                    ent.insert(Transform::from(spatial.transform().to_bevy_transform()))
+                        .insert(GodotGlobalTransform::from(spatial.global_transform()));
                 }
 
                 if let Some(node2d) = node.try_get::<Node2D>() {
-                    // gdnative's Transform2D has buggy modifiers
-                    let mut transform = GodotTransform2D::IDENTITY.translated(node2d.position());
-                    transform.set_scale(node2d.scale());
-                    transform.set_rotation(node2d.rotation() as f32);
-
-                    ent.insert(Transform2D::from(transform));
+                    ent.insert(Transform2D::from(node2d.get_transform()))
+                        .insert(GodotGlobalTransform2D::from(node2d.get_global_transform()));
                 }
 
                 let node = node.get::<Node>();
 
-                if node.has_signal("body_entered") {
-                    debug!(target: "godot_scene_tree_collisions", body_id = node.get_instance_id(), "has body_entered signal");
-                    node.connect(
-                        "body_entered",
-                        collision_watcher,
-                        "collision_event",
-                        VariantArray::from_iter(&[
-                            Variant::new(node.claim()),
-                            Variant::new(CollisionEventType::Started),
-                        ])
-                        .into_shared(),
-                        0,
-                    )
-                    .unwrap();
-                    node.connect(
-                        "body_exited",
-                        collision_watcher,
-                        "collision_event",
-                        VariantArray::from_iter(&[
-                            Variant::new(node.claim()),
-                            Variant::new(CollisionEventType::Ended),
-                        ])
-                        .into_shared(),
-                        0,
-                    )
-                    .unwrap();
-
-                    ent.insert(Collisions::default());
-                }
+                connect_collision_signals(&mut ent, node, collision_watcher);
+                insert_collision_layers(&mut ent, node);
+                insert_control_rect(&mut ent, node, control_watcher);
 
                 ent.insert(Groups::from(&*node));
 
                 let ent = ent.id();
-                ent_mapping.insert(node.get_instance_id(), ent);
-
-                if node.get_instance_id() != scene_root.get_instance_id() {
-                    let parent =
-                        unsafe { node.get_parent().unwrap().assume_safe().get_instance_id() };
-                    commands
-                        .entity(*ent_mapping.get(&parent).unwrap())
-                        .push_children(&[ent]);
+                entity_map.insert(node.get_instance_id(), ent);
+
+                let new_parent = find_mirrored_ancestor(node, entity_map);
+                if old_parent != new_parent {
+                    match new_parent {
+                        Some(parent) => {
+                            commands.entity(parent).push_children(&[ent]);
+                        }
+                        None => {
+                            commands.entity(ent).remove_parent();
+                        }
+                    }
+
+                    if old_parent.is_some() || was_detached {
+                        reparented.send(NodeReparented {
+                            entity: ent,
+                            old_parent,
+                            new_parent,
+                        });
+                    }
                 }
             }
             SceneTreeEventType::NodeRemoved => {
-                commands.entity(ent.unwrap()).despawn_recursive();
+                let ent = match ent {
+                    Some(ent) => ent,
+                    None => continue,
+                };
+
+                let instance_id = node.instance_id();
+                match node.try_get::<Node>() {
+                    // The node was moved within the tree, the following NodeAdded event fixes up its parent
+                    Some(node) if node.is_inside_tree() => {}
+                    Some(node) if config.keep_detached_nodes => {
+                        let mut ent = commands.entity(ent);
+                        ent.insert(Detached);
+
+                        if node.get_parent().is_none() {
+                            ent.remove_parent();
+                        }
+                    }
+                    _ => {
+                        entity_map.remove_instance_id(instance_id);
+                        commands.add(DespawnFreedNode(ent));
+                    }
+                }
             }
             SceneTreeEventType::NodeRenamed => {
-                commands
-                    .entity(ent.unwrap())
-                    .insert(Name::from(node.get::<Node>().name().to_string()));
+                if let Some(ent) = ent {
+                    commands
+                        .entity(ent)
+                        .insert(Name::from(node.get::<Node>().name().to_string()));
+                }
             }
         }
     }
 }
 
+/// Despawns the entity of a freed node, moving its children to its parent
+///
+/// Children that mirror Godot nodes are despawned by their own events, but Bevy-only children would be
+/// lost with `despawn_recursive`. Entities in the hierarchy may already have been despawned by earlier
+/// commands, so they are checked before being touched.
+struct DespawnFreedNode(Entity);
+
+impl Command for DespawnFreedNode {
+    fn write(self, world: &mut World) {
+        let ent = self.0;
+        let (parent, children) = match world.get_entity(ent) {
+            Some(ent) => (
+                ent.get::<Parent>().map(Parent::get),
+                ent.get::<Children>()
+                    .map(|children| children.to_vec())
+                    .unwrap_or_default(),
+            ),
+            None => return,
+        };
+
+        let parent = parent.filter(|parent| world.get_entity(*parent).is_some());
+        let children: Vec<Entity> = children
+            .into_iter()
+            .filter(|child| world.get_entity(*child).is_some())
+            .collect();
+
+        match parent {
+            Some(parent) => {
+                world.entity_mut(parent).push_children(&children);
+                world.entity_mut(ent).remove_parent();
+            }
+            None => {
+                for child in children {
+                    world.entity_mut(child).remove_parent();
+                }
+            }
+        }
+
+        world.despawn(ent);
+    }
+}
+
+fn despawn_freed_detached_nodes(
+    mut commands: Commands,
+    mut entity_map: ResMut<GodotEntityMap>,
+    mut detached: Query<(Entity, &mut ErasedGodotRef), With<Detached>>,
+) {
+    for (ent, mut reference) in detached.iter_mut() {
+        // detached nodes are outside the tree, so freeing them doesn't send a NodeRemoved event
+        if !reference.is_valid() {
+            entity_map.remove_instance_id(reference.instance_id());
+            commands.add(DespawnFreedNode(ent));
+        }
+    }
+}
+
+/// Finds the entity of the closest ancestor that is mirrored in the ECS, skipping filtered out nodes
+fn find_mirrored_ancestor(node: TRef<Node>, entity_map: &GodotEntityMap) -> Option<Entity> {
+    let mut parent = node.get_parent();
+
+    while let Some(node) = parent {
+        let node = unsafe { node.assume_safe() };
+        if let Some(ent) = entity_map.get_entity(node.get_instance_id()) {
+            return Some(ent);
+        }
+
+        parent = node.get_parent();
+    }
+
+    None
+}
+
 fn read_scene_tree_events(
     mut commands: Commands,
     mut scene_tree: SceneTreeRef,
     mut event_reader: EventReader<SceneTreeEvent>,
-    mut entities: Query<(&mut ErasedGodotRef, Entity)>,
+    mut entity_map: ResMut<GodotEntityMap>,
+    config: Res<SceneTreeConfig>,
+    hierarchy: Query<(Option<&Parent>, Option<&Detached>)>,
+    mut reparented: EventWriter<NodeReparented>,
 ) {
     create_scene_tree_entity(
         &mut commands,
         event_reader.iter().cloned(),
         &mut scene_tree,
-        &mut entities,
+        &mut entity_map,
+        &config,
+        &hierarchy,
+        &mut reparented,
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn freed_node_keeps_bevy_children() {
+        let mut world = World::new();
+        let root = world.spawn_empty().id();
+        let node = world.spawn_empty().id();
+        let mirrored_child = world.spawn_empty().id();
+        let bevy_child = world.spawn_empty().id();
+        world.entity_mut(root).push_children(&[node]);
+        world
+            .entity_mut(node)
+            .push_children(&[mirrored_child, bevy_child]);
+
+        // children are removed from the scene tree before their parents
+        DespawnFreedNode(mirrored_child).write(&mut world);
+        DespawnFreedNode(node).write(&mut world);
+
+        assert!(world.get_entity(node).is_none());
+        assert!(world.get_entity(mirrored_child).is_none());
+        assert_eq!(world.get::<Parent>(bevy_child).map(Parent::get), Some(root));
+        assert_eq!(
+            world
+                .get::<Children>(root)
+                .map(|children| children.to_vec()),
+            Some(vec![bevy_child])
+        );
+    }
+
+    #[test]
+    fn freed_root_node_orphans_bevy_children() {
+        let mut world = World::new();
+        let node = world.spawn_empty().id();
+        let bevy_child = world.spawn_empty().id();
+        world.entity_mut(node).push_children(&[bevy_child]);
+
+        DespawnFreedNode(node).write(&mut world);
+        // commands for already despawned entities are ignored
+        DespawnFreedNode(node).write(&mut world);
+
+        assert!(world.get_entity(node).is_none());
+        assert!(world.get::<Parent>(bevy_child).is_none());
+    }
+}
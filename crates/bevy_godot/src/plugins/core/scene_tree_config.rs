@@ -0,0 +1,169 @@
+use crate::prelude::*;
+
+/// Bevy Resource that configures which Godot nodes are mirrored into the ECS
+///
+/// A node is mirrored when it matches any of the `include` filters (or no `include` filters are set),
+/// matches none of the `exclude` filters and does not have a truthy `ignore_meta_key` metadata entry.
+/// Nodes that are already bound to an entity, such as instanced [`GodotScene`]s, are always mirrored.
+///
+/// The configuration is read as nodes are added, so it should be inserted while building the app.
+#[derive(Resource, Debug, Clone)]
+pub struct SceneTreeConfig {
+    pub include: Vec<NodeFilter>,
+    pub exclude: Vec<NodeFilter>,
+    pub ignore_meta_key: Option<String>,
+    /// Keep the entities of nodes that are removed from the scene tree without being freed, marking them as [`Detached`]
+    pub keep_detached_nodes: bool,
+}
+
+impl Default for SceneTreeConfig {
+    fn default() -> Self {
+        Self {
+            include: vec![],
+            exclude: vec![],
+            ignore_meta_key: Some("bevy_ignore".to_string()),
+            keep_detached_nodes: false,
+        }
+    }
+}
+
+impl SceneTreeConfig {
+    /// Only mirror nodes that match one of the included filters
+    pub fn include(mut self, filter: NodeFilter) -> Self {
+        self.include.push(filter);
+        self
+    }
+
+    /// Never mirror nodes that match this filter
+    pub fn exclude(mut self, filter: NodeFilter) -> Self {
+        self.exclude.push(filter);
+        self
+    }
+
+    /// Sets the metadata key that opts a node out of mirroring, or disables the check with `None`
+    pub fn with_ignore_meta_key(mut self, key: Option<&str>) -> Self {
+        self.ignore_meta_key = key.map(str::to_string);
+        self
+    }
+
+    /// Keeps the entities of nodes that are removed from the scene tree but not freed
+    pub fn with_detached_nodes_kept(mut self, keep: bool) -> Self {
+        self.keep_detached_nodes = keep;
+        self
+    }
+
+    pub fn should_mirror(&self, node: TRef<Node>) -> bool {
+        if let Some(key) = &self.ignore_meta_key {
+            if node.has_meta(key.as_str())
+                && node
+                    .get_meta(key.as_str(), Variant::nil())
+                    .try_to::<bool>()
+                    .unwrap_or(true)
+            {
+                return false;
+            }
+        }
+
+        if !self.include.is_empty() && !self.include.iter().any(|filter| filter.matches(node)) {
+            return false;
+        }
+
+        !self.exclude.iter().any(|filter| filter.matches(node))
+    }
+}
+
+/// A predicate on a Godot node used by [`SceneTreeConfig`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeFilter {
+    /// Matches nodes in the group
+    Group(String),
+    /// Matches nodes of the class or any class inheriting from it
+    Class(String),
+    /// Matches nodes whose absolute path matches the glob, e.g. `/root/Main/HUD/**`
+    ///
+    /// `*` matches within a single path segment, `**` matches across segments and `?` matches a single character.
+    Path(String),
+}
+
+impl NodeFilter {
+    pub fn matches(&self, node: TRef<Node>) -> bool {
+        match self {
+            NodeFilter::Group(group) => node.is_in_group(group.as_str()),
+            NodeFilter::Class(class) => node.is_class(class.as_str()),
+            NodeFilter::Path(glob) => path_glob_matches(glob, &node.get_path().to_string()),
+        }
+    }
+}
+
+fn path_glob_matches(glob: &str, path: &str) -> bool {
+    fn matches(glob: &[char], path: &[char]) -> bool {
+        match glob {
+            [] => path.is_empty(),
+            ['*', '*', rest @ ..] => (0..=path.len()).any(|i| matches(rest, &path[i..])),
+            ['*', rest @ ..] => (0..=path.len())
+                .take_while(|&i| i == 0 || path[i - 1] != '/')
+                .any(|i| matches(rest, &path[i..])),
+            ['?', rest @ ..] => {
+                matches!(path.first(), Some(c) if *c != '/') && matches(rest, &path[1..])
+            }
+            [c, rest @ ..] => path.first() == Some(c) && matches(rest, &path[1..]),
+        }
+    }
+
+    let glob = glob.chars().collect::<Vec<_>>();
+    let path = path.chars().collect::<Vec<_>>();
+    matches(&glob, &path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::path_glob_matches;
+
+    #[test]
+    fn star_matches_within_a_segment() {
+        assert!(path_glob_matches("/root/Main/*", "/root/Main/Player"));
+        assert!(path_glob_matches("/root/*/Player", "/root/Main/Player"));
+        assert!(path_glob_matches("/root/Main/Enemy*", "/root/Main/Enemy"));
+        assert!(!path_glob_matches(
+            "/root/Main/*",
+            "/root/Main/Player/Sprite"
+        ));
+        assert!(!path_glob_matches("/root/*", "/root/Main/Player"));
+    }
+
+    #[test]
+    fn double_star_matches_across_segments() {
+        assert!(path_glob_matches(
+            "/root/Main/HUD/**",
+            "/root/Main/HUD/Health"
+        ));
+        assert!(path_glob_matches(
+            "/root/Main/HUD/**",
+            "/root/Main/HUD/Panel/Label"
+        ));
+        assert!(path_glob_matches(
+            "/root/**/Label",
+            "/root/Main/HUD/Panel/Label"
+        ));
+        assert!(path_glob_matches("**", "/root/Main"));
+        assert!(!path_glob_matches("/root/Main/HUD/**", "/root/Main/Player"));
+    }
+
+    #[test]
+    fn question_mark_matches_one_character_within_a_segment() {
+        assert!(path_glob_matches("/root/Main/Enemy?", "/root/Main/Enemy1"));
+        assert!(!path_glob_matches("/root/Main/Enemy?", "/root/Main/Enemy"));
+        assert!(!path_glob_matches(
+            "/root/Main/Enemy?",
+            "/root/Main/Enemy12"
+        ));
+        assert!(!path_glob_matches("/root/Main?Player", "/root/Main/Player"));
+    }
+
+    #[test]
+    fn literal_globs_match_exactly() {
+        assert!(path_glob_matches("/root/Main", "/root/Main"));
+        assert!(!path_glob_matches("/root/Main", "/root/Main/Player"));
+        assert!(!path_glob_matches("/root/Main/Player", "/root/Main"));
+    }
+}
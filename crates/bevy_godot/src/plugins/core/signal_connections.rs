@@ -0,0 +1,374 @@
+use crate::prelude::{
+    godot_prelude::{GodotError, VariantArray},
+    *,
+};
+use lazy_static::lazy_static;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+};
+
+pub struct GodotSignalConnectionsPlugin;
+
+impl Plugin for GodotSignalConnectionsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(connect_signals.in_base_set(CoreSet::Last))
+            .add_system(
+                track_signal_connections
+                    .in_base_set(CoreSet::Last)
+                    .after(connect_signals),
+            )
+            .add_system(
+                disconnect_removed_signal_connections
+                    .in_base_set(CoreSet::Last)
+                    .after(track_signal_connections),
+            )
+            .init_resource::<TrackedSignalConnections>();
+    }
+}
+
+/// Bevy Component that owns the Godot signal connections made through it
+///
+/// Signals are forwarded to Bevy as [`GodotSignal`] events. The connections are disconnected when the
+/// component is removed or its entity is despawned.
+///
+/// Godot only allows one connection of a signal to the signal watcher, so components connecting the same
+/// signal with the same flags share it, and it stays connected until the last of them disconnects.
+#[derive(Component, Debug, Default)]
+pub struct SignalConnections {
+    connections: Vec<OwnedConnection>,
+}
+
+/// A share of a Godot connection, identified by its id so that stale shares don't release a new connection
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct OwnedConnection {
+    instance_id: i64,
+    signal_name: String,
+    id: u64,
+}
+
+/// A Godot connection of a signal to the signal watcher, shared by the [`SignalConnections`] connecting it
+#[derive(Debug)]
+struct SharedConnection {
+    id: u64,
+    owners: usize,
+    flags: i64,
+}
+
+lazy_static! {
+    static ref SHARED_CONNECTIONS: Mutex<HashMap<(i64, String), SharedConnection>> =
+        Mutex::new(HashMap::new());
+}
+
+static NEXT_CONNECTION_ID: AtomicU64 = AtomicU64::new(0);
+
+impl SignalConnections {
+    pub fn connect(
+        &mut self,
+        node: &mut ErasedGodotRef,
+        signal_name: &str,
+    ) -> Result<(), SignalConnectionError> {
+        self.connect_with_flags(node, signal_name, 0)
+    }
+
+    /// Connects the signal so that it is disconnected after being emitted once
+    pub fn connect_oneshot(
+        &mut self,
+        node: &mut ErasedGodotRef,
+        signal_name: &str,
+    ) -> Result<(), SignalConnectionError> {
+        self.connect_with_flags(node, signal_name, Object::CONNECT_ONESHOT)
+    }
+
+    /// Connects the signal so that it is forwarded at the end of the Godot frame instead of when emitted
+    pub fn connect_deferred(
+        &mut self,
+        node: &mut ErasedGodotRef,
+        signal_name: &str,
+    ) -> Result<(), SignalConnectionError> {
+        self.connect_with_flags(node, signal_name, Object::CONNECT_DEFERRED)
+    }
+
+    /// Connects the signal with `Object::CONNECT_*` flags
+    pub fn connect_with_flags(
+        &mut self,
+        node: &mut ErasedGodotRef,
+        signal_name: &str,
+        flags: i64,
+    ) -> Result<(), SignalConnectionError> {
+        let instance_id = node.instance_id();
+        if self.position(instance_id, signal_name).is_some() {
+            return Err(SignalConnectionError::AlreadyConnected(
+                signal_name.to_string(),
+            ));
+        }
+
+        let id = acquire_connection(node, signal_name, flags)?;
+        self.connections.push(OwnedConnection {
+            instance_id,
+            signal_name: signal_name.to_string(),
+            id,
+        });
+
+        Ok(())
+    }
+
+    /// Disconnects the signal, unless it is still connected through other [`SignalConnections`]
+    pub fn disconnect(
+        &mut self,
+        node: &mut ErasedGodotRef,
+        signal_name: &str,
+    ) -> Result<(), SignalConnectionError> {
+        let index = self
+            .position(node.instance_id(), signal_name)
+            .ok_or_else(|| SignalConnectionError::NotConnected(signal_name.to_string()))?;
+
+        release_connection(&self.connections.remove(index))
+    }
+
+    /// The connections made through this component as `(instance id, signal name)`
+    ///
+    /// Oneshot connections are listed until they are disconnected, even if they have already fired.
+    pub fn connections(&self) -> impl Iterator<Item = (i64, &str)> {
+        self.connections
+            .iter()
+            .map(|connection| (connection.instance_id, connection.signal_name.as_str()))
+    }
+
+    fn position(&self, instance_id: i64, signal_name: &str) -> Option<usize> {
+        self.connections.iter().position(|connection| {
+            connection.instance_id == instance_id && connection.signal_name == signal_name
+        })
+    }
+}
+
+/// Bevy Component that connects the signals of the entity's node once it is mirrored
+///
+/// Can be inserted on any mirrored entity or on a [`GodotScene`] before it is spawned. The connections are
+/// owned by the entity's [`SignalConnections`], which is inserted if missing.
+///
+/// ```ignore
+/// commands.entity(button).insert(ConnectSignals(vec!["pressed", "toggled"]));
+/// ```
+#[derive(Component, Debug, Default, Clone)]
+pub struct ConnectSignals(pub Vec<&'static str>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignalConnectionError {
+    /// The node was freed
+    InvalidNode(GodotRefError),
+    /// The node has no signal with this name
+    UnknownSignal(String),
+    AlreadyConnected(String),
+    NotConnected(String),
+    /// The autoload that forwards signals to Bevy isn't in the scene tree
+    MissingSignalWatcher,
+    Godot(GodotError),
+}
+
+impl From<GodotRefError> for SignalConnectionError {
+    fn from(e: GodotRefError) -> Self {
+        SignalConnectionError::InvalidNode(e)
+    }
+}
+
+impl std::fmt::Display for SignalConnectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SignalConnectionError::InvalidNode(e) => write!(f, "invalid node: {e}"),
+            SignalConnectionError::UnknownSignal(name) => write!(f, "node has no signal {name}"),
+            SignalConnectionError::AlreadyConnected(name) => {
+                write!(f, "signal {name} is already connected")
+            }
+            SignalConnectionError::NotConnected(name) => write!(f, "signal {name} isn't connected"),
+            SignalConnectionError::MissingSignalWatcher => {
+                write!(f, "the godot signal watcher isn't in the scene tree")
+            }
+            SignalConnectionError::Godot(e) => write!(f, "failed to connect signal: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for SignalConnectionError {}
+
+pub(crate) fn connect_with_flags(
+    node: &mut ErasedGodotRef,
+    signal_name: &str,
+    flags: i64,
+) -> Result<(), SignalConnectionError> {
+    let node = node.checked_get::<Object>()?;
+    let signal_watcher = signal_watcher()?;
+
+    if !node.has_signal(signal_name) {
+        return Err(SignalConnectionError::UnknownSignal(
+            signal_name.to_string(),
+        ));
+    }
+
+    if node.is_connected(signal_name, signal_watcher, "event") {
+        return Err(SignalConnectionError::AlreadyConnected(
+            signal_name.to_string(),
+        ));
+    }
+
+    node.connect(
+        signal_name,
+        signal_watcher,
+        "event",
+        // must match `signal_watcher::BOUND_ARG_COUNT`
+        VariantArray::from_iter([Variant::new(node), Variant::new(signal_name)]).into_shared(),
+        flags,
+    )
+    .map_err(SignalConnectionError::Godot)
+}
+
+/// Connects the signal or shares its existing connection, returning the id of the connection
+fn acquire_connection(
+    node: &mut ErasedGodotRef,
+    signal_name: &str,
+    flags: i64,
+) -> Result<u64, SignalConnectionError> {
+    let key = (node.instance_id(), signal_name.to_string());
+    let object = node.checked_get::<Object>()?;
+    let signal_watcher = signal_watcher()?;
+    let mut shared = SHARED_CONNECTIONS.lock().unwrap();
+
+    match shared.get_mut(&key) {
+        // godot disconnects oneshot connections once they fire, which leaves their entry behind
+        Some(connection) if object.is_connected(signal_name, signal_watcher, "event") => {
+            if connection.flags != flags {
+                return Err(SignalConnectionError::AlreadyConnected(
+                    signal_name.to_string(),
+                ));
+            }
+
+            connection.owners += 1;
+            return Ok(connection.id);
+        }
+        _ => {}
+    }
+
+    // fails if the signal was connected without being owned, e.g. by `connect_godot_signal`
+    connect_with_flags(node, signal_name, flags)?;
+
+    let id = NEXT_CONNECTION_ID.fetch_add(1, Ordering::Relaxed);
+    shared.insert(
+        key,
+        SharedConnection {
+            id,
+            owners: 1,
+            flags,
+        },
+    );
+    Ok(id)
+}
+
+/// Gives up a share of the connection, disconnecting the signal once the last share is released
+fn release_connection(connection: &OwnedConnection) -> Result<(), SignalConnectionError> {
+    let key = (connection.instance_id, connection.signal_name.clone());
+    let mut shared = SHARED_CONNECTIONS.lock().unwrap();
+
+    match shared.get_mut(&key) {
+        Some(shared_connection) if shared_connection.id == connection.id => {
+            shared_connection.owners -= 1;
+            if shared_connection.owners > 0 {
+                return Ok(());
+            }
+
+            shared.remove(&key);
+        }
+        // a oneshot connection that fired was replaced by a new connection
+        _ => {
+            return Err(SignalConnectionError::NotConnected(
+                connection.signal_name.clone(),
+            ))
+        }
+    }
+
+    let node = unsafe { TRef::<Object>::try_from_instance_id(connection.instance_id) }
+        .ok_or(SignalConnectionError::InvalidNode(GodotRefError::Freed))?;
+    disconnect(node, &connection.signal_name)
+}
+
+fn disconnect(node: TRef<Object>, signal_name: &str) -> Result<(), SignalConnectionError> {
+    let signal_watcher = signal_watcher()?;
+
+    if !node.is_connected(signal_name, signal_watcher, "event") {
+        return Err(SignalConnectionError::NotConnected(signal_name.to_string()));
+    }
+
+    node.disconnect(signal_name, signal_watcher, "event");
+    Ok(())
+}
+
+#[allow(clippy::type_complexity)]
+fn connect_signals(
+    mut commands: Commands,
+    mut entities: Query<
+        (
+            Entity,
+            &ConnectSignals,
+            &mut ErasedGodotRef,
+            Option<&mut SignalConnections>,
+        ),
+        Or<(Added<ErasedGodotRef>, Changed<ConnectSignals>)>,
+    >,
+    _scene_tree: SceneTreeRef,
+) {
+    for (ent, signals, mut reference, connections) in entities.iter_mut() {
+        let mut new_connections = None;
+        let connections = match connections {
+            Some(connections) => connections.into_inner(),
+            None => new_connections.insert(SignalConnections::default()),
+        };
+
+        let instance_id = reference.instance_id();
+        for signal_name in &signals.0 {
+            if connections.position(instance_id, signal_name).is_some() {
+                continue;
+            }
+
+            if let Err(e) = connections.connect(&mut reference, signal_name) {
+                error!(?ent, signal_name, "failed to connect signal: {e}");
+            }
+        }
+
+        if let Some(connections) = new_connections {
+            commands.entity(ent).insert(connections);
+        }
+    }
+}
+
+/// The connections of every [`SignalConnections`], kept to disconnect them after the component is removed
+#[derive(Resource, Debug, Default)]
+struct TrackedSignalConnections(HashMap<Entity, Vec<OwnedConnection>>);
+
+fn track_signal_connections(
+    mut tracked: ResMut<TrackedSignalConnections>,
+    entities: Query<(Entity, &SignalConnections), Changed<SignalConnections>>,
+) {
+    for (ent, connections) in entities.iter() {
+        tracked.0.insert(ent, connections.connections.clone());
+    }
+}
+
+fn disconnect_removed_signal_connections(
+    mut tracked: ResMut<TrackedSignalConnections>,
+    mut removed: RemovedComponents<SignalConnections>,
+    still_connected: Query<(), With<SignalConnections>>,
+) {
+    for ent in removed.iter() {
+        // the component may have been removed and inserted again
+        if still_connected.contains(ent) {
+            continue;
+        }
+
+        for connection in tracked.0.remove(&ent).into_iter().flatten() {
+            // oneshot connections may have been disconnected by godot already
+            let _ = release_connection(&connection);
+        }
+    }
+}
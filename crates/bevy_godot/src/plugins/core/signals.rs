@@ -1,4 +1,6 @@
-use crate::prelude::{godot_prelude::VariantArray, *};
+use crate::prelude::*;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicI64, Ordering};
 
 pub struct GodotSignalsPlugin;
 
@@ -37,11 +39,69 @@ impl GodotSignal {
         self.origin.clone()
     }
 
+    /// Returns the entity mirroring the node that emitted the signal
+    pub fn origin_entity(&self, entity_map: &GodotEntityMap) -> Option<Entity> {
+        entity_map.get_entity(self.origin.instance_id())
+    }
+
     pub fn args(&self) -> &[Variant] {
         &self.args
     }
 }
 
+/// A Bevy Event decoded from a Godot signal, see `#[derive(GodotSignal)]`
+///
+/// Signal arguments are decoded in order into the fields with `FromVariant`, and a field marked
+/// with `#[origin]` is resolved to the entity (or [`ErasedGodotRef`]) of the emitting node.
+pub trait TypedGodotSignal: Sized + Send + Sync + 'static {
+    const SIGNAL_NAME: &'static str;
+
+    /// Returns `None` if the arguments don't decode or the origin isn't mirrored
+    fn from_godot_signal(signal: &GodotSignal, entity_map: &GodotEntityMap) -> Option<Self>;
+}
+
+/// Registers `T` as a Bevy Event that is sent for every matching [`GodotSignal`]
+pub struct TypedGodotSignalPlugin<T>(PhantomData<fn() -> T>);
+
+impl<T> Default for TypedGodotSignalPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: TypedGodotSignal> Plugin for TypedGodotSignalPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_system(
+            write_typed_godot_signal_events::<T>
+                .in_base_set(CoreSet::First)
+                .after(write_godot_signal_events)
+                .before(Events::<T>::update_system),
+        )
+        .add_event::<T>();
+    }
+}
+
+fn write_typed_godot_signal_events<T: TypedGodotSignal>(
+    mut signals: EventReader<GodotSignal>,
+    mut event_writer: EventWriter<T>,
+    entity_map: Res<GodotEntityMap>,
+) {
+    for signal in signals
+        .iter()
+        .filter(|signal| signal.name() == T::SIGNAL_NAME)
+    {
+        match T::from_godot_signal(signal, &entity_map) {
+            Some(event) => event_writer.send(event),
+            None => debug!(
+                target: "godot_signal",
+                signal = ?signal,
+                "failed to decode signal as {}",
+                std::any::type_name::<T>()
+            ),
+        }
+    }
+}
+
 #[doc(hidden)]
 pub struct GodotSignalReader(pub std::sync::mpsc::Receiver<GodotSignal>);
 
@@ -52,32 +112,23 @@ fn write_godot_signal_events(
     event_writer.send_batch(events.0.try_iter());
 }
 
+/// Forwards the node's signal to Bevy as a [`GodotSignal`] event
+///
+/// Signals with any number of arguments are supported. Use [`SignalConnections`] to be able to disconnect the signal,
+/// or [`ConnectSignals`] to connect signals by entity. Taking the [`SceneTreeRef`] keeps the caller on the main thread.
 pub fn connect_godot_signal(
     node: &mut ErasedGodotRef,
     signal_name: &str,
-    scene_tree: &mut SceneTreeRef,
-) {
-    let node = node.get::<Object>();
-    let scene_root = unsafe { scene_tree.get().root().unwrap().assume_safe() };
-
-    let signal_watcher = scene_root
-        .get_node("/root/Autoload/GodotSignalWatcher")
-        .unwrap();
-
-    node.connect(
-        signal_name,
-        signal_watcher,
-        "event",
-        VariantArray::from_iter(
-            [
-                Variant::new(signal_watcher),
-                Variant::new(node),
-                Variant::new(signal_name),
-            ]
-            .into_iter(),
-        )
-        .into_shared(),
-        0,
-    )
-    .unwrap();
+    _scene_tree: &mut SceneTreeRef,
+) -> Result<(), SignalConnectionError> {
+    connect_with_flags(node, signal_name, 0)
+}
+
+/// Instance id of the node that forwards connected signals to Bevy, set when the autoload is ready
+pub(crate) static SIGNAL_WATCHER_INSTANCE_ID: AtomicI64 = AtomicI64::new(0);
+
+/// The node that forwards connected signals to Bevy
+pub(crate) fn signal_watcher<'a>() -> Result<TRef<'a, Node>, SignalConnectionError> {
+    unsafe { TRef::try_from_instance_id(SIGNAL_WATCHER_INSTANCE_ID.load(Ordering::Relaxed)) }
+        .ok_or(SignalConnectionError::MissingSignalWatcher)
 }
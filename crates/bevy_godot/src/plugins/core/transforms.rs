@@ -1,5 +1,12 @@
-use crate::prelude::{godot_prelude::*, *};
-use std::marker::PhantomData;
+use crate::{
+    init_macro::transform_watcher::{TransformWatcher, TransformWatcher2D, TRANSFORM_WATCHER_META},
+    prelude::{godot_prelude::*, *},
+};
+use std::{
+    collections::HashSet,
+    marker::PhantomData,
+    sync::mpsc::{Receiver, Sender},
+};
 
 #[derive(Debug, Component, Default, Copy, Clone)]
 pub struct Transform {
@@ -154,47 +161,364 @@ impl IntoGodotTransform for bevy::prelude::Transform {
     }
 }
 
-#[derive(Debug, Component, Clone, Copy)]
-pub struct Transform2D(pub gdnative::prelude::Transform2D);
+/// Bevy Component mirroring the local transform of a `Node2D`
+///
+/// `rotation` and `skew` are in radians. `skew` tilts the y axis away from being perpendicular to the x axis,
+/// Godot only keeps it until the node's rotation or scale is set.
+#[derive(Debug, Component, Clone, Copy, PartialEq)]
+pub struct Transform2D {
+    pub translation: Vec2,
+    pub rotation: f32,
+    pub scale: Vec2,
+    pub skew: f32,
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Transform2D {
+    pub const IDENTITY: Self = Self {
+        translation: Vec2::ZERO,
+        rotation: 0.0,
+        scale: Vec2::ONE,
+        skew: 0.0,
+    };
+
+    pub fn from_translation(translation: Vec2) -> Self {
+        Self {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub fn with_rotation(mut self, rotation: f32) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    pub fn with_scale(mut self, scale: Vec2) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub fn with_skew(mut self, skew: f32) -> Self {
+        self.skew = skew;
+        self
+    }
+
+    /// Returns the transform on the xy plane of a 3D Bevy transform, ignoring `skew`
+    pub fn as_bevy(&self) -> bevy::prelude::Transform {
+        bevy::prelude::Transform {
+            translation: self.translation.extend(0.0),
+            rotation: bevy::prelude::Quat::from_rotation_z(self.rotation),
+            scale: self.scale.extend(1.0),
+        }
+    }
+
+    pub fn as_godot(&self) -> gdnative::prelude::Transform2D {
+        self.to_godot_transform_2d()
+    }
+
+    /// Returns true if the transform converts to `godot_transform` up to the rounding errors of the conversion
+    ///
+    /// Converting between the matrix and its parts isn't exact, so comparing them exactly would keep writing
+    /// back a transform read from Godot.
+    pub fn is_equal_approx_godot(&self, godot_transform: gdnative::prelude::Transform2D) -> bool {
+        // godot's CMP_EPSILON, relative to the length of the axis
+        const EPSILON: f32 = 0.00001;
+
+        fn axis_eq(a: Vector2, b: Vector2) -> bool {
+            let tolerance = EPSILON * a.length().max(b.length()).max(1.0);
+            (a - b).length() <= tolerance
+        }
+
+        let transform = self.as_godot();
+        axis_eq(transform.a, godot_transform.a)
+            && axis_eq(transform.b, godot_transform.b)
+            && axis_eq(transform.origin, godot_transform.origin)
+    }
+}
 
 impl From<gdnative::prelude::Transform2D> for Transform2D {
     fn from(transform: gdnative::prelude::Transform2D) -> Self {
+        transform.to_bevy_transform_2d()
+    }
+}
+
+pub trait IntoBevyTransform2D {
+    fn to_bevy_transform_2d(self) -> Transform2D;
+}
+
+impl IntoBevyTransform2D for gdnative::prelude::Transform2D {
+    fn to_bevy_transform_2d(self) -> Transform2D {
+        use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+        let det = self.a.x * self.b.y - self.a.y * self.b.x;
+        let sign = if det < 0.0 { -1.0 } else { 1.0 };
+
+        let rotation = self.a.y.atan2(self.a.x);
+        let scale = Vec2::new(self.a.length(), sign * self.b.length());
+
+        // the y axis is perpendicular to the x axis when there is no skew
+        let b = self.b * sign;
+        let skew = (b.y.atan2(b.x) - rotation - FRAC_PI_2 + PI).rem_euclid(TAU) - PI;
+
+        Transform2D {
+            translation: Vec2::new(self.origin.x, self.origin.y),
+            rotation,
+            scale,
+            skew,
+        }
+    }
+}
+
+pub trait IntoGodotTransform2D {
+    fn to_godot_transform_2d(self) -> gdnative::prelude::Transform2D;
+}
+
+impl IntoGodotTransform2D for Transform2D {
+    fn to_godot_transform_2d(self) -> gdnative::prelude::Transform2D {
+        let (sin, cos) = self.rotation.sin_cos();
+        let (skewed_sin, skewed_cos) = (self.rotation + self.skew).sin_cos();
+
+        gdnative::prelude::Transform2D {
+            a: Vector2::new(cos, sin) * self.scale.x,
+            b: Vector2::new(-skewed_sin, skewed_cos) * self.scale.y,
+            origin: Vector2::new(self.translation.x, self.translation.y),
+        }
+    }
+}
+
+/// Bevy Component mirroring the global transform of a `Spatial`
+///
+/// Read from Godot whenever the node or one of its ancestors moves, so changes made to [`Transform`]s in Bevy
+/// are reflected in the following update.
+#[derive(Debug, Component, Default, Copy, Clone, PartialEq)]
+pub struct GodotGlobalTransform(gdnative::prelude::Transform);
+
+impl GodotGlobalTransform {
+    pub fn as_godot(&self) -> &gdnative::prelude::Transform {
+        &self.0
+    }
+
+    pub fn to_bevy(&self) -> bevy::prelude::Transform {
+        self.0.to_bevy_transform()
+    }
+
+    pub fn translation(&self) -> Vec3 {
+        let origin = self.0.origin;
+        Vec3::new(origin.x, origin.y, origin.z)
+    }
+}
+
+impl From<gdnative::prelude::Transform> for GodotGlobalTransform {
+    fn from(transform: gdnative::prelude::Transform) -> Self {
         Self(transform)
     }
 }
 
-impl std::ops::Deref for Transform2D {
-    type Target = gdnative::prelude::Transform2D;
-    fn deref(&self) -> &Self::Target {
+/// Bevy Component mirroring the global transform of a `Node2D`, see [`GodotGlobalTransform`]
+#[derive(Debug, Component, Copy, Clone, PartialEq)]
+pub struct GodotGlobalTransform2D(gdnative::prelude::Transform2D);
+
+impl Default for GodotGlobalTransform2D {
+    fn default() -> Self {
+        Self(gdnative::prelude::Transform2D::IDENTITY)
+    }
+}
+
+impl GodotGlobalTransform2D {
+    pub fn as_godot(&self) -> &gdnative::prelude::Transform2D {
         &self.0
     }
+
+    pub fn to_bevy(&self) -> Transform2D {
+        self.0.to_bevy_transform_2d()
+    }
+
+    pub fn translation(&self) -> Vec2 {
+        let origin = self.0.origin;
+        Vec2::new(origin.x, origin.y)
+    }
 }
 
-impl std::ops::DerefMut for Transform2D {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+impl From<gdnative::prelude::Transform2D> for GodotGlobalTransform2D {
+    fn from(transform: gdnative::prelude::Transform2D) -> Self {
+        Self(transform)
     }
 }
 
+/// Bevy Component that sets the direction in which the [`Transform`] or [`Transform2D`] of an entity is synchronized
+///
+/// Entities without it are synchronized in both directions. Entities that don't read Godot when they are
+/// spawned don't get a transform watcher, so their [`GodotGlobalTransform`] or [`GodotGlobalTransform2D`]
+/// isn't updated either.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransformSyncMode {
+    /// Only changes made to the Godot node are read back into the component
+    GodotToBevy,
+    /// Only changes made to the component are written to the Godot node
+    BevyToGodot,
+    #[default]
+    Bidirectional,
+    Disabled,
+}
+
+impl TransformSyncMode {
+    pub fn reads_godot(&self) -> bool {
+        matches!(self, Self::GodotToBevy | Self::Bidirectional)
+    }
+
+    pub fn writes_godot(&self) -> bool {
+        matches!(self, Self::BevyToGodot | Self::Bidirectional)
+    }
+}
+
+#[doc(hidden)]
+pub struct TransformChangedReader(pub Receiver<i64>);
+
+#[doc(hidden)]
+pub struct TransformWatcherSender(pub Sender<i64>);
+
+/// Instance ids of the nodes whose transform changed since the last update
+#[derive(Resource, Debug, Default)]
+struct ChangedGodotTransforms(HashSet<i64>);
+
+/// Synchronizes [`Transform`] and [`Transform2D`] with the `Spatial` and `Node2D` nodes of their entity
+///
+/// Only the transforms of nodes that moved are read back in the PreUpdate stage. Godot 3 only sends
+/// `NOTIFICATION_TRANSFORM_CHANGED` to the node and its script, so it is received by a watcher node that is
+/// added as a child of every node whose [`TransformSyncMode`] reads Godot. The watcher is never mirrored into
+/// the ECS, but GDScript sees it in `get_children()` and `get_child_count()`; insert `BevyToGodot` or
+/// `Disabled` before the Last stage of the frame the entity is spawned to keep the node's children unchanged.
 pub struct GodotTransformsPlugin;
 
 impl Plugin for GodotTransformsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system(post_update_godot_transforms.in_base_set(CoreSet::Last))
+        app.add_system(watch_godot_transforms.in_base_set(CoreSet::Last))
+            .add_system(post_update_godot_transforms.in_base_set(CoreSet::Last))
+            .add_system(
+                read_godot_transform_changes
+                    .in_base_set(CoreSet::PreUpdate)
+                    .before(pre_update_godot_transforms)
+                    .before(pre_update_godot_transforms_2d),
+            )
             .add_system(pre_update_godot_transforms.in_base_set(CoreSet::PreUpdate))
             .add_system(post_update_godot_transforms_2d.in_base_set(CoreSet::Last))
-            .add_system(pre_update_godot_transforms_2d.in_base_set(CoreSet::PreUpdate));
+            .add_system(pre_update_godot_transforms_2d.in_base_set(CoreSet::PreUpdate))
+            .add_system(
+                update_godot_global_transforms
+                    .in_base_set(CoreSet::PreUpdate)
+                    .after(read_godot_transform_changes),
+            )
+            .add_system(
+                update_godot_global_transforms_2d
+                    .in_base_set(CoreSet::PreUpdate)
+                    .after(read_godot_transform_changes),
+            )
+            .init_resource::<ChangedGodotTransforms>();
     }
 }
 
-fn post_update_godot_transforms(
+fn watch_godot_transforms(
     _scene_tree: SceneTreeRef,
+    sender: NonSend<TransformWatcherSender>,
     mut entities: Query<
-        (&Transform, &mut ErasedGodotRef),
-        Or<(Added<Transform>, Changed<Transform>)>,
+        (&mut ErasedGodotRef, Option<&TransformSyncMode>),
+        Or<(
+            Added<Transform>,
+            Added<Transform2D>,
+            Added<GodotGlobalTransform>,
+            Added<GodotGlobalTransform2D>,
+            Changed<TransformSyncMode>,
+        )>,
     >,
 ) {
-    for (transform, mut reference) in entities.iter_mut() {
+    for (mut reference, sync_mode) in entities.iter_mut() {
+        if !sync_mode.copied().unwrap_or_default().reads_godot() {
+            continue;
+        }
+
+        // the node keeps its watcher when it is mirrored by a new entity
+        if has_transform_watcher(reference.get::<Node>()) {
+            continue;
+        }
+
+        if let Some(spatial) = reference.try_get::<Spatial>() {
+            let watcher = TransformWatcher::new_instance();
+            watcher
+                .map_mut(|script, _base| script.notification_channel = Some(sender.0.clone()))
+                .unwrap();
+            watcher.base().set_meta(TRANSFORM_WATCHER_META, true);
+
+            spatial.add_child(watcher.into_base().into_shared(), false);
+        } else if let Some(node2d) = reference.try_get::<Node2D>() {
+            let watcher = TransformWatcher2D::new_instance();
+            watcher
+                .map_mut(|script, _base| script.notification_channel = Some(sender.0.clone()))
+                .unwrap();
+            watcher.base().set_meta(TRANSFORM_WATCHER_META, true);
+
+            node2d.add_child(watcher.into_base().into_shared(), false);
+        }
+    }
+}
+
+/// Returns true if the node has a watcher that reports to the app
+///
+/// Copies of a watcher made by `Node.duplicate` have no channel and free themselves, so they don't count.
+fn has_transform_watcher(node: TRef<Node>) -> bool {
+    (0..node.get_child_count())
+        .filter_map(|i| node.get_child(i))
+        .any(|child| {
+            let child = unsafe { child.assume_safe() };
+            if let Some(watcher) = child
+                .cast::<Spatial>()
+                .and_then(|spatial| spatial.cast_instance::<TransformWatcher>())
+            {
+                watcher
+                    .map(|watcher, _base| watcher.notification_channel.is_some())
+                    .unwrap_or(false)
+            } else if let Some(watcher) = child
+                .cast::<Node2D>()
+                .and_then(|node2d| node2d.cast_instance::<TransformWatcher2D>())
+            {
+                watcher
+                    .map(|watcher, _base| watcher.notification_channel.is_some())
+                    .unwrap_or(false)
+            } else {
+                false
+            }
+        })
+}
+
+fn read_godot_transform_changes(
+    reader: NonSendMut<TransformChangedReader>,
+    mut changed: ResMut<ChangedGodotTransforms>,
+) {
+    changed.0.clear();
+    changed.0.extend(reader.0.try_iter());
+}
+
+pub(crate) fn post_update_godot_transforms(
+    _scene_tree: SceneTreeRef,
+    mut entities: Query<
+        (&Transform, &mut ErasedGodotRef, Option<&TransformSyncMode>),
+        Or<(
+            Added<Transform>,
+            Changed<Transform>,
+            Changed<TransformSyncMode>,
+        )>,
+    >,
+) {
+    for (transform, mut reference, sync_mode) in entities.iter_mut() {
+        if !sync_mode.copied().unwrap_or_default().writes_godot() {
+            continue;
+        }
+
         let obj = reference.get::<Spatial>();
         if obj.transform() != *transform.as_godot() {
             obj.set_transform(*transform.as_godot());
@@ -204,9 +528,24 @@ fn post_update_godot_transforms(
 
 fn pre_update_godot_transforms(
     _scene_tree: SceneTreeRef,
-    mut entities: Query<(&mut Transform, &mut ErasedGodotRef)>,
+    changed: Res<ChangedGodotTransforms>,
+    entity_map: Res<GodotEntityMap>,
+    mut entities: Query<(
+        &mut Transform,
+        &mut ErasedGodotRef,
+        Option<&TransformSyncMode>,
+    )>,
 ) {
-    for (mut transform, mut reference) in entities.iter_mut() {
+    for ent in changed.0.iter().filter_map(|id| entity_map.get_entity(*id)) {
+        let (mut transform, mut reference, sync_mode) = match entities.get_mut(ent) {
+            Ok(components) => components,
+            Err(_) => continue,
+        };
+
+        if !sync_mode.copied().unwrap_or_default().reads_godot() {
+            continue;
+        }
+
         let godot_transform = reference.get::<Spatial>().transform();
         if *transform.as_godot() != godot_transform {
             *transform.as_godot_mut() = godot_transform;
@@ -214,39 +553,233 @@ fn pre_update_godot_transforms(
     }
 }
 
-fn post_update_godot_transforms_2d(
+pub(crate) fn post_update_godot_transforms_2d(
     _scene_tree: SceneTreeRef,
     mut entities: Query<
-        (&Transform2D, &mut ErasedGodotRef),
-        Or<(Added<Transform2D>, Changed<Transform2D>)>,
+        (
+            &Transform2D,
+            &mut ErasedGodotRef,
+            Option<&TransformSyncMode>,
+        ),
+        Or<(
+            Added<Transform2D>,
+            Changed<Transform2D>,
+            Changed<TransformSyncMode>,
+        )>,
     >,
 ) {
-    for (transform, mut reference) in entities.iter_mut() {
+    for (transform, mut reference, sync_mode) in entities.iter_mut() {
+        if !sync_mode.copied().unwrap_or_default().writes_godot() {
+            continue;
+        }
+
         let obj = reference.get::<Node2D>();
+        if !transform.is_equal_approx_godot(obj.get_transform()) {
+            obj.set_transform(transform.as_godot());
+        }
+    }
+}
 
-        let mut obj_transform = GodotTransform2D::IDENTITY.translated(obj.position());
-        obj_transform.set_rotation(obj.rotation() as f32);
-        obj_transform.set_scale(obj.scale());
+fn pre_update_godot_transforms_2d(
+    _scene_tree: SceneTreeRef,
+    changed: Res<ChangedGodotTransforms>,
+    entity_map: Res<GodotEntityMap>,
+    mut entities: Query<(
+        &mut Transform2D,
+        &mut ErasedGodotRef,
+        Option<&TransformSyncMode>,
+    )>,
+) {
+    for ent in changed.0.iter().filter_map(|id| entity_map.get_entity(*id)) {
+        let (mut transform, mut reference, sync_mode) = match entities.get_mut(ent) {
+            Ok(components) => components,
+            Err(_) => continue,
+        };
+
+        if !sync_mode.copied().unwrap_or_default().reads_godot() {
+            continue;
+        }
 
-        if obj_transform != **transform {
-            obj.set_transform(**transform);
+        let obj_transform = reference.get::<Node2D>().get_transform();
+        if !transform.is_equal_approx_godot(obj_transform) {
+            *transform = obj_transform.to_bevy_transform_2d();
         }
     }
 }
 
-fn pre_update_godot_transforms_2d(
+fn update_godot_global_transforms(
     _scene_tree: SceneTreeRef,
-    mut entities: Query<(&mut Transform2D, &mut ErasedGodotRef)>,
+    changed: Res<ChangedGodotTransforms>,
+    entity_map: Res<GodotEntityMap>,
+    mut entities: Query<(&mut GodotGlobalTransform, &mut ErasedGodotRef)>,
 ) {
-    for (mut transform, mut reference) in entities.iter_mut() {
-        let obj = reference.get::<Node2D>();
+    for ent in changed.0.iter().filter_map(|id| entity_map.get_entity(*id)) {
+        let (mut global_transform, mut reference) = match entities.get_mut(ent) {
+            Ok(components) => components,
+            Err(_) => continue,
+        };
+
+        let godot_transform = reference.get::<Spatial>().global_transform();
+        if global_transform.0 != godot_transform {
+            global_transform.0 = godot_transform;
+        }
+    }
+}
+
+fn update_godot_global_transforms_2d(
+    _scene_tree: SceneTreeRef,
+    changed: Res<ChangedGodotTransforms>,
+    entity_map: Res<GodotEntityMap>,
+    mut entities: Query<(&mut GodotGlobalTransform2D, &mut ErasedGodotRef)>,
+) {
+    for ent in changed.0.iter().filter_map(|id| entity_map.get_entity(*id)) {
+        let (mut global_transform, mut reference) = match entities.get_mut(ent) {
+            Ok(components) => components,
+            Err(_) => continue,
+        };
+
+        let godot_transform = reference.get::<Node2D>().get_global_transform();
+        if global_transform.0 != godot_transform {
+            global_transform.0 = godot_transform;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
 
-        let mut obj_transform = GodotTransform2D::IDENTITY.translated(obj.position());
-        obj_transform.set_rotation(obj.rotation() as f32);
-        obj_transform.set_scale(obj.scale());
+    fn assert_vector2_eq(actual: Vector2, expected: Vector2) {
+        assert!(
+            (actual.x - expected.x).abs() < 1e-4 && (actual.y - expected.y).abs() < 1e-4,
+            "expected {expected:?}, found {actual:?}"
+        );
+    }
+
+    fn assert_godot_round_trip(transform: GodotTransform2D) {
+        let bevy = transform.to_bevy_transform_2d();
+        let round_trip = bevy.to_godot_transform_2d();
+        assert_vector2_eq(round_trip.a, transform.a);
+        assert_vector2_eq(round_trip.b, transform.b);
+        assert_vector2_eq(round_trip.origin, transform.origin);
+
+        // the sync systems must not write back a transform they read
+        assert!(
+            bevy.is_equal_approx_godot(transform),
+            "{bevy:?} read from {transform:?} would be written back as {round_trip:?}"
+        );
+    }
+
+    fn assert_bevy_round_trip(transform: Transform2D) {
+        let round_trip = transform.to_godot_transform_2d().to_bevy_transform_2d();
+        assert!(
+            (round_trip.translation - transform.translation)
+                .abs()
+                .max_element()
+                < 1e-4
+                && (round_trip.rotation - transform.rotation).abs() < 1e-4
+                && (round_trip.scale - transform.scale).abs().max_element() < 1e-4
+                && (round_trip.skew - transform.skew).abs() < 1e-4,
+            "expected {transform:?}, found {round_trip:?}"
+        );
+    }
+
+    #[test]
+    fn identity_round_trips() {
+        assert_godot_round_trip(GodotTransform2D::IDENTITY);
+        assert_bevy_round_trip(Transform2D::IDENTITY);
+    }
+
+    #[test]
+    fn rotation_round_trips() {
+        let transform = Transform2D::from_translation(Vec2::new(10.0, -4.0))
+            .with_rotation(1.2)
+            .with_scale(Vec2::new(2.0, 0.5));
+
+        assert_bevy_round_trip(transform);
+        assert_godot_round_trip(transform.as_godot());
+    }
+
+    #[test]
+    fn negative_y_scale_round_trips() {
+        let transform = Transform2D::from_translation(Vec2::new(3.0, 7.0))
+            .with_rotation(-0.7)
+            .with_scale(Vec2::new(1.5, -2.0));
+
+        assert_bevy_round_trip(transform);
+
+        // a flipped y axis is read back as a negative y scale rather than a half turn of skew
+        let godot = GodotTransform2D {
+            a: Vector2::new(1.0, 0.0),
+            b: Vector2::new(0.0, -3.0),
+            origin: Vector2::new(0.0, 0.0),
+        };
+        let bevy = godot.to_bevy_transform_2d();
+        assert!((bevy.scale - Vec2::new(1.0, -3.0)).abs().max_element() < 1e-4);
+        assert!(bevy.rotation.abs() < 1e-4 && bevy.skew.abs() < 1e-4);
+        assert_godot_round_trip(godot);
+    }
+
+    #[test]
+    fn skew_wraps_around_the_rotation() {
+        // the angle of the y axis wraps past pi, which must not leak into the skew
+        for (rotation, skew) in [(3.0, 0.5), (-3.0, -0.5), (3.1, 0.3), (-2.5, 1.0)] {
+            let transform = Transform2D::IDENTITY
+                .with_rotation(rotation)
+                .with_scale(Vec2::new(1.0, -1.5))
+                .with_skew(skew);
+
+            assert_bevy_round_trip(transform);
+            assert_godot_round_trip(transform.as_godot());
+        }
+    }
+
+    #[test]
+    fn skewed_godot_transform_round_trips() {
+        assert_godot_round_trip(GodotTransform2D {
+            a: Vector2::new(0.8, 1.9),
+            b: Vector2::new(-2.2, 0.4),
+            origin: Vector2::new(-5.0, 12.0),
+        });
+        assert_godot_round_trip(GodotTransform2D {
+            a: Vector2::new(-1.0, 0.2),
+            b: Vector2::new(0.5, 1.3),
+            origin: Vector2::new(1.0, 1.0),
+        });
+    }
 
-        if obj_transform != **transform {
-            **transform = obj_transform;
+    #[test]
+    fn read_transforms_are_not_written_back() {
+        // xorshift, to cover many transforms without a dependency on rand
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut random = |min: f32, max: f32| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            min + (state >> 40) as f32 / (1u64 << 24) as f32 * (max - min)
+        };
+
+        for _ in 0..100_000 {
+            let scale_y = random(0.01, 100.0) * if random(0.0, 1.0) < 0.5 { -1.0 } else { 1.0 };
+            let transform = Transform2D::from_translation(Vec2::new(
+                random(-10_000.0, 10_000.0),
+                random(-10_000.0, 10_000.0),
+            ))
+            .with_rotation(random(-PI, PI))
+            .with_scale(Vec2::new(random(0.01, 100.0), scale_y))
+            .with_skew(random(-1.5, 1.5));
+
+            let godot = transform.as_godot();
+            let read = godot.to_bevy_transform_2d();
+            assert!(
+                read.is_equal_approx_godot(godot),
+                "{read:?} read from {godot:?} would be written back as {:?}",
+                read.as_godot()
+            );
+
+            // writing the read transform is a no-op, so it must not be read back as a change either
+            assert!(read.is_equal_approx_godot(read.as_godot()));
         }
     }
 }
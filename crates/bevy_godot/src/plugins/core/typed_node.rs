@@ -0,0 +1,135 @@
+use crate::prelude::{
+    godot_prelude::{
+        AnimatedSprite, Area, Area2D, AudioStreamPlayer, Button, Camera, Camera2D, CanvasItem,
+        ClassDB, CollisionObject, CollisionObject2D, KinematicBody, KinematicBody2D, Label,
+        MeshInstance, PathFollow2D, PhysicsBody, PhysicsBody2D, RigidBody, RigidBody2D, Sprite,
+        StaticBody, StaticBody2D, VisibilityNotifier2D,
+    },
+    *,
+};
+use bevy::ecs::system::EntityCommands;
+use gdnative::prelude::{GodotObject, ManuallyManaged};
+use std::marker::PhantomData;
+
+/// A typed handle to the Godot node mirrored by an entity
+///
+/// A `GodotNode<T>` is inserted for every supported class in the node's class hierarchy, so
+/// `Query<&mut GodotNode<AnimatedSprite>>` only matches entities whose node is an `AnimatedSprite`.
+#[derive(Component)]
+pub struct GodotNode<T: GodotObject<Memory = ManuallyManaged>> {
+    reference: ErasedGodotRef,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: GodotObject<Memory = ManuallyManaged>> GodotNode<T> {
+    fn new(reference: ErasedGodotRef) -> Self {
+        Self {
+            reference,
+            phantom: PhantomData,
+        }
+    }
+
+    /// # Panics
+    /// Panics if the node was freed, see [`Self::checked_get`]
+    pub fn get(&mut self) -> TRef<T> {
+        self.reference.get::<T>()
+    }
+
+    pub fn try_get(&mut self) -> Option<TRef<T>> {
+        self.reference.try_get::<T>()
+    }
+
+    /// Returns the node, checking that it is still alive
+    pub fn checked_get(&mut self) -> Result<TRef<T>, GodotRefError> {
+        self.reference.checked_get::<T>()
+    }
+
+    pub fn erased(&self) -> &ErasedGodotRef {
+        &self.reference
+    }
+
+    pub fn instance_id(&self) -> i64 {
+        self.reference.instance_id()
+    }
+}
+
+impl<T: GodotObject<Memory = ManuallyManaged>> Clone for GodotNode<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.reference.clone())
+    }
+}
+
+impl<T: GodotObject<Memory = ManuallyManaged>> std::fmt::Debug for GodotNode<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple(&format!("GodotNode<{}>", T::class_name()))
+            .field(&self.reference)
+            .finish()
+    }
+}
+
+macro_rules! node_classes {
+    ($($class:ident => $marker:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Marker component for entities whose node is a `", stringify!($class), "` or inherits from it")]
+            #[derive(Component, Debug, Default, Clone, Copy)]
+            pub struct $marker;
+        )*
+
+        fn insert_class_components(
+            ent: &mut EntityCommands,
+            reference: &ErasedGodotRef,
+            class_name: &str,
+        ) {
+            $(
+                if class_name == <$class as GodotObject>::class_name() {
+                    ent.insert(($marker, GodotNode::<$class>::new(reference.clone())));
+                    return;
+                }
+            )*
+        }
+    };
+}
+
+node_classes! {
+    Node => IsNode,
+    CanvasItem => IsCanvasItem,
+    Node2D => IsNode2D,
+    Control => IsControl,
+    Spatial => IsSpatial,
+    CollisionObject => IsCollisionObject,
+    CollisionObject2D => IsCollisionObject2D,
+    PhysicsBody => IsPhysicsBody,
+    PhysicsBody2D => IsPhysicsBody2D,
+    Area => IsArea,
+    Area2D => IsArea2D,
+    KinematicBody => IsKinematicBody,
+    KinematicBody2D => IsKinematicBody2D,
+    RigidBody => IsRigidBody,
+    RigidBody2D => IsRigidBody2D,
+    StaticBody => IsStaticBody,
+    StaticBody2D => IsStaticBody2D,
+    Camera => IsCamera,
+    Camera2D => IsCamera2D,
+    MeshInstance => IsMeshInstance,
+    Sprite => IsSprite,
+    AnimatedSprite => IsAnimatedSprite,
+    PathFollow2D => IsPathFollow2D,
+    VisibilityNotifier2D => IsVisibilityNotifier2D,
+    Label => IsLabel,
+    Button => IsButton,
+    AudioStreamPlayer => IsAudioStreamPlayer,
+}
+
+/// Inserts the marker components and [`GodotNode`] handles for every supported class the node inherits from
+pub(crate) fn insert_node_class_components(
+    ent: &mut EntityCommands,
+    reference: &mut ErasedGodotRef,
+) {
+    let class_db = ClassDB::godot_singleton();
+    let mut class_name = reference.get::<Object>().get_class();
+
+    while !class_name.is_empty() {
+        insert_class_components(ent, reference, &class_name.to_string());
+        class_name = class_db.get_parent_class(class_name);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::prelude::{
+    godot_prelude::{Dictionary, ToVariant, VariantArray, VariantType},
+    *,
+};
+use std::marker::PhantomData;
+
+/// Declares a custom signal on the node so that GDScript can `connect` to it
+///
+/// `args` are the names and types of the signal's arguments. Does nothing if the node already has the signal.
+pub fn add_user_signal(
+    node: &mut ErasedGodotRef,
+    signal_name: &str,
+    args: &[(&str, VariantType)],
+) -> Result<(), GodotRefError> {
+    declare_user_signal(node.checked_get::<Object>()?, signal_name, args);
+    Ok(())
+}
+
+fn declare_user_signal(node: TRef<Object>, signal_name: &str, args: &[(&str, VariantType)]) {
+    if node.has_signal(signal_name) {
+        return;
+    }
+
+    let arguments = VariantArray::new();
+    for (name, ty) in args {
+        let argument = Dictionary::new();
+        argument.insert("name", *name);
+        argument.insert("type", *ty as i64);
+        arguments.push(argument.into_shared());
+    }
+
+    node.add_user_signal(signal_name, arguments.into_shared());
+}
+
+/// Bevy Event that is re-emitted as the signal `name` of the entity's node, with `value` as its argument
+///
+/// Sent events are only emitted when [`BevyToGodotSignalPlugin<T>`] is added. The signal is declared
+/// with an untyped argument if the node doesn't have it yet, see [`add_user_signal`] to declare its type.
+#[derive(Debug, Clone)]
+pub struct BevyToGodotSignal<T> {
+    pub entity: Entity,
+    pub name: String,
+    pub value: T,
+}
+
+impl<T> BevyToGodotSignal<T> {
+    pub fn new(entity: Entity, name: impl ToString, value: T) -> Self {
+        Self {
+            entity,
+            name: name.to_string(),
+            value,
+        }
+    }
+}
+
+/// Emits every [`BevyToGodotSignal<T>`] as a Godot signal in the Last stage
+pub struct BevyToGodotSignalPlugin<T>(PhantomData<fn() -> T>);
+
+impl<T> Default for BevyToGodotSignalPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: ToVariant + Send + Sync + 'static> Plugin for BevyToGodotSignalPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_system(emit_bevy_to_godot_signals::<T>.in_base_set(CoreSet::Last))
+            .add_event::<BevyToGodotSignal<T>>();
+    }
+}
+
+fn emit_bevy_to_godot_signals<T: ToVariant + Send + Sync + 'static>(
+    mut events: EventReader<BevyToGodotSignal<T>>,
+    mut entities: Query<&mut ErasedGodotRef>,
+    _scene_tree: SceneTreeRef,
+) {
+    for event in events.iter() {
+        let node = match get_entity_node(
+            &mut entities,
+            event.entity,
+            format_args!("emit signal {}", event.name),
+        ) {
+            Some(node) => node,
+            None => continue,
+        };
+
+        declare_user_signal(node, &event.name, &[("value", VariantType::Nil)]);
+        node.emit_signal(event.name.as_str(), &[event.value.to_variant()]);
+    }
+}
@@ -1,6 +1,7 @@
 pub mod assets;
 pub mod core;
 pub mod packed_scene;
+pub mod spawn_node;
 
 use bevy::app::*;
 
@@ -10,6 +11,7 @@ impl Plugin for DefaultGodotPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugin(core::GodotCorePlugin)
             .add_plugin(packed_scene::PackedScenePlugin)
+            .add_plugin(spawn_node::SpawnNodePlugin)
             .add_plugin(assets::GodotAssetsPlugin);
     }
 }
@@ -74,6 +74,7 @@ fn spawn_scene(
         Without<GodotSceneSpawned>,
     >,
     mut assets: ResMut<Assets<GodotResource>>,
+    mut entity_map: ResMut<GodotEntityMap>,
 ) {
     for (mut scene, ent, transform2d, transform) in new_scenes.iter_mut() {
         let resource_loader = ResourceLoader::godot_singleton();
@@ -103,7 +104,7 @@ fn spawn_scene(
                     .assume_safe()
                     .cast::<Node2D>()
                     .unwrap()
-                    .set_transform(**transform2d);
+                    .set_transform(transform2d.as_godot());
             }
         }
 
@@ -125,9 +126,12 @@ fn spawn_scene(
             },
         }
 
+        let instance = unsafe { ErasedGodotRef::new(instance.assume_unique()) };
+        entity_map.insert(instance.instance_id(), ent);
+
         commands
             .entity(ent)
-            .insert(unsafe { ErasedGodotRef::new(instance.assume_unique()) })
+            .insert(instance)
             .insert(GodotSceneSpawned);
     }
 }
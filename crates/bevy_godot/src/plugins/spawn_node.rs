@@ -0,0 +1,183 @@
+use crate::prelude::{
+    godot_prelude::{ClassDB, Reference},
+    *,
+};
+use bevy::ecs::entity::Entities;
+use std::collections::HashMap;
+
+pub struct SpawnNodePlugin;
+
+impl Plugin for SpawnNodePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(spawn_node.in_base_set(CoreSet::PostUpdate))
+            .add_system(
+                free_despawned_nodes
+                    .in_base_set(CoreSet::PostUpdate)
+                    .after(spawn_node),
+            )
+            .init_resource::<SpawnedGodotNodes>();
+    }
+}
+
+/// A to-be-instanced Godot node.
+///
+/// Entities with a [`SpawnGodotNode`] will have a node of `class` instanced in the next PostUpdate stage and
+/// the instance will be added as an [`ErasedGodotRef`]. The node is added as a child of the node mirrored by `parent`,
+/// falling back to the node of the entity's Bevy [`Parent`] and then the current scene.
+///
+/// The node is freed when the entity is despawned, removing the component leaves the node to the entity's
+/// [`ErasedGodotRef`] without freeing it.
+#[derive(Component, Debug, Clone)]
+pub struct SpawnGodotNode {
+    pub class: &'static str,
+    pub parent: Option<Entity>,
+    pub name: Option<String>,
+}
+
+impl Default for SpawnGodotNode {
+    fn default() -> Self {
+        Self {
+            class: "Node",
+            parent: None,
+            name: None,
+        }
+    }
+}
+
+impl SpawnGodotNode {
+    pub fn new(class: &'static str) -> Self {
+        Self {
+            class,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the entity whose node the new node is added under
+    pub fn with_parent(mut self, parent: Entity) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    pub fn with_name(mut self, name: impl ToString) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+}
+
+#[derive(Component, Debug, Default)]
+struct GodotNodeSpawned;
+
+/// Instance ids of the nodes owned by their entities through a [`SpawnGodotNode`]
+#[derive(Resource, Debug, Default)]
+struct SpawnedGodotNodes(HashMap<Entity, i64>);
+
+fn spawn_node(
+    mut commands: Commands,
+    mut scene_tree: SceneTreeRef,
+    mut entity_map: ResMut<GodotEntityMap>,
+    mut spawned_nodes: ResMut<SpawnedGodotNodes>,
+    new_nodes: Query<
+        (
+            &SpawnGodotNode,
+            Entity,
+            Option<&Parent>,
+            Option<&Transform2D>,
+            Option<&Transform>,
+            Option<&TransformSyncMode>,
+        ),
+        Without<GodotNodeSpawned>,
+    >,
+    pending_parents: Query<
+        (),
+        Or<(
+            (With<SpawnGodotNode>, Without<GodotNodeSpawned>),
+            With<GodotScene>,
+        )>,
+    >,
+) {
+    for (spawn, ent, bevy_parent, transform2d, transform, sync_mode) in new_nodes.iter() {
+        let parent = match spawn.parent.or_else(|| bevy_parent.map(Parent::get)) {
+            Some(parent) => match entity_map.get_instance_id(parent) {
+                Some(instance_id) => Some(instance_id),
+                // the parent's node hasn't been instanced yet
+                None if pending_parents.contains(parent) => continue,
+                None => {
+                    debug!(entity = ?ent, parent = ?parent, "parent has no godot node, adding node to the current scene");
+                    None
+                }
+            },
+            None => None,
+        };
+
+        let instance = ClassDB::godot_singleton().instance(spawn.class);
+        let instance = match instance.to_object::<Node>() {
+            Some(instance) => unsafe { instance.assume_safe() },
+            None => {
+                // references are freed with the variant, other objects have to be freed
+                if instance.to_object::<Reference>().is_none() {
+                    if let Some(object) = instance.to_object::<Object>() {
+                        unsafe { object.assume_unique() }.free();
+                    }
+                }
+
+                error!(class = spawn.class, "failed to instance godot node");
+                commands.entity(ent).insert(GodotNodeSpawned);
+                continue;
+            }
+        };
+
+        if let Some(name) = &spawn.name {
+            instance.set_name(name.as_str());
+        }
+
+        if sync_mode.copied().unwrap_or_default().writes_godot() {
+            if let Some(transform2d) = transform2d {
+                if let Some(node2d) = instance.cast::<Node2D>() {
+                    node2d.set_transform(transform2d.as_godot());
+                }
+            }
+
+            if let Some(transform) = transform {
+                if let Some(spatial) = instance.cast::<Spatial>() {
+                    spatial.set_transform(*transform.as_godot());
+                }
+            }
+        }
+
+        match parent.and_then(|parent| unsafe { TRef::<Node>::try_from_instance_id(parent) }) {
+            Some(parent) => parent.add_child(instance, true),
+            None => scene_tree.add_to_scene(instance),
+        }
+
+        let instance = unsafe { ErasedGodotRef::from_instance_id(instance.get_instance_id()) };
+        entity_map.insert(instance.instance_id(), ent);
+        spawned_nodes.0.insert(ent, instance.instance_id());
+
+        commands
+            .entity(ent)
+            .insert(instance)
+            .insert(GodotNodeSpawned);
+    }
+}
+
+fn free_despawned_nodes(
+    entities: &Entities,
+    mut spawned_nodes: ResMut<SpawnedGodotNodes>,
+    mut removed: RemovedComponents<SpawnGodotNode>,
+) {
+    for ent in removed.iter() {
+        let instance_id = match spawned_nodes.0.remove(&ent) {
+            Some(instance_id) => instance_id,
+            None => continue,
+        };
+
+        // the component was removed from an entity that is still alive, which keeps the node
+        if entities.contains(ent) {
+            continue;
+        }
+
+        if let Some(node) = unsafe { TRef::<Node>::try_from_instance_id(instance_id) } {
+            node.queue_free();
+        }
+    }
+}
@@ -1,4 +1,4 @@
-pub use crate::plugins::{assets::GodotResource, core::*, packed_scene::*};
+pub use crate::plugins::{assets::GodotResource, core::*, packed_scene::*, spawn_node::*};
 
 pub mod bevy_prelude {
     pub use bevy::prelude::*;
@@ -33,4 +33,4 @@ pub use crate::bevy_godot_init;
 pub use crate::GodotPlugin;
 
 pub use crate::node_tree_view::NodeTreeView;
-pub use bevy_godot_proc_macro::NodeTreeView;
+pub use bevy_godot_proc_macro::{GodotProperties, GodotSignal, NodeTreeView};
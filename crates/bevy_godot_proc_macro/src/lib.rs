@@ -120,3 +120,276 @@ fn create_get_node_expr(field: &Field) -> Result<TokenStream2> {
 
     Ok(expr)
 }
+
+#[proc_macro_derive(GodotProperties, attributes(godot))]
+pub fn derive_godot_properties(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+
+    let expanded = godot_properties(input).unwrap_or_else(Error::into_compile_error);
+
+    TokenStream::from(expanded)
+}
+
+fn godot_properties(input: DeriveInput) -> Result<TokenStream2> {
+    let item = &input.ident;
+    let data_struct = match &input.data {
+        Data::Struct(data_struct) => data_struct,
+        _ => {
+            return Err(Error::new_spanned(
+                input,
+                "GodotProperties must be used on structs",
+            ))
+        }
+    };
+
+    let to_variant = quote! { ::bevy_godot::prelude::godot_prelude::ToVariant };
+
+    let mut field_errors = vec![];
+    let mut reads = TokenStream2::new();
+    let mut writes = TokenStream2::new();
+
+    for (index, field) in data_struct.fields.iter().enumerate() {
+        let property = match godot_property_name(field) {
+            Ok(Some(property)) => property,
+            Ok(None) => continue,
+            Err(e) => {
+                field_errors.push(e);
+                continue;
+            }
+        };
+
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        };
+        let ty = &field.ty;
+
+        reads.extend(quote! {
+            if let Ok(value) = node.get(#property).try_to::<#ty>() {
+                if component.#member != value {
+                    component.#member = value;
+                }
+            }
+        });
+
+        writes.extend(quote! {
+            let value = #to_variant::to_variant(&self.#member);
+            if node.get(#property) != value {
+                node.set(#property, value);
+            }
+        });
+    }
+
+    if !field_errors.is_empty() {
+        let mut error = field_errors[0].clone();
+        error.extend(field_errors[1..].iter().cloned());
+
+        return Err(error);
+    }
+
+    let godot_properties = quote! { ::bevy_godot::prelude::GodotProperties };
+    let mut_ = quote! { ::bevy_godot::prelude::Mut };
+    let object = quote! { ::bevy_godot::prelude::Object };
+    let tref = quote! { ::bevy_godot::prelude::TRef };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let expanded = quote! {
+        impl #impl_generics #godot_properties for #item #ty_generics #where_clause {
+            fn read_properties(component: &mut #mut_<Self>, node: #tref<#object>) {
+                #reads
+            }
+
+            fn write_properties(&self, node: #tref<#object>) {
+                #writes
+            }
+        }
+    };
+
+    Ok(expanded)
+}
+
+/// Returns the Godot property a field mirrors, or `None` if the field is skipped
+fn godot_property_name(field: &Field) -> Result<Option<LitStr>> {
+    let mut property = field
+        .ident
+        .as_ref()
+        .map(|ident| LitStr::new(&ident.to_string(), ident.span()));
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("godot"))
+    {
+        let nested = match attr.parse_meta()? {
+            Meta::List(list) => list.nested,
+            meta => {
+                return Err(Error::new_spanned(
+                    meta,
+                    "GodotProperties: expected #[godot(property = \"..\")] or #[godot(skip)]",
+                ))
+            }
+        };
+
+        for meta in nested {
+            match meta {
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(lit),
+                    ..
+                })) if path.is_ident("property") => property = Some(lit),
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => return Ok(None),
+                meta => {
+                    return Err(Error::new_spanned(
+                        meta,
+                        "GodotProperties: expected #[godot(property = \"..\")] or #[godot(skip)]",
+                    ))
+                }
+            }
+        }
+    }
+
+    property.map(Some).ok_or_else(|| {
+        Error::new_spanned(
+            field,
+            "GodotProperties: tuple struct fields must have a #[godot(property = \"..\")]",
+        )
+    })
+}
+
+#[proc_macro_derive(GodotSignal, attributes(signal, origin))]
+pub fn derive_godot_signal(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+
+    let expanded = typed_godot_signal(input).unwrap_or_else(Error::into_compile_error);
+
+    TokenStream::from(expanded)
+}
+
+fn typed_godot_signal(input: DeriveInput) -> Result<TokenStream2> {
+    let item = &input.ident;
+    let data_struct = match &input.data {
+        Data::Struct(data_struct) => data_struct,
+        _ => {
+            return Err(Error::new_spanned(
+                input,
+                "GodotSignal must be used on structs",
+            ))
+        }
+    };
+
+    let signal_name: LitStr = input
+        .attrs
+        .iter()
+        .find_map(|attr| {
+            attr.path.is_ident("signal").then_some(())?;
+            attr.parse_args().ok()
+        })
+        .ok_or_else(|| Error::new_spanned(&input, "GodotSignal: missing #[signal(\"..\")]"))?;
+
+    let mut arg_index = 0usize;
+    let mut field_errors = vec![];
+    let field_exprs = data_struct
+        .fields
+        .iter()
+        .map(|field| {
+            let expr = if field.attrs.iter().any(|attr| attr.path.is_ident("origin")) {
+                create_signal_origin_expr(field)
+            } else {
+                let ty = &field.ty;
+                let index = arg_index;
+                arg_index += 1;
+                Ok(quote! { signal.args().get(#index)?.try_to::<#ty>().ok()? })
+            };
+
+            match expr {
+                Ok(expr) => {
+                    if let Some(name) = &field.ident {
+                        quote! { #name : #expr, }
+                    } else {
+                        quote! { #expr, }
+                    }
+                }
+                Err(e) => {
+                    field_errors.push(e);
+                    TokenStream2::new()
+                }
+            }
+        })
+        .collect::<TokenStream2>();
+
+    if !field_errors.is_empty() {
+        let mut error = field_errors[0].clone();
+        error.extend(field_errors[1..].iter().cloned());
+
+        return Err(error);
+    }
+
+    let self_expr = match data_struct.fields {
+        Fields::Named(_) => quote! { Self { #field_exprs } },
+        Fields::Unnamed(_) => quote! { Self ( #field_exprs ) },
+        Fields::Unit => quote! { Self },
+    };
+
+    let typed_godot_signal = quote! { ::bevy_godot::prelude::TypedGodotSignal };
+    let godot_signal = quote! { ::bevy_godot::prelude::GodotSignal };
+    let entity_map = quote! { ::bevy_godot::prelude::GodotEntityMap };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let expanded = quote! {
+        impl #impl_generics #typed_godot_signal for #item #ty_generics #where_clause {
+            const SIGNAL_NAME: &'static str = #signal_name;
+
+            #[allow(unused_variables)]
+            fn from_godot_signal(signal: &#godot_signal, entity_map: &#entity_map) -> Option<Self> {
+                Some(#self_expr)
+            }
+        }
+    };
+
+    Ok(expanded)
+}
+
+fn create_signal_origin_expr(field: &Field) -> Result<TokenStream2> {
+    if is_type_named(&field.ty, "Entity") {
+        Ok(quote! { signal.origin_entity(entity_map)? })
+    } else if matches!(option_inner_type(&field.ty), Some(ty) if is_type_named(ty, "Entity")) {
+        Ok(quote! { signal.origin_entity(entity_map) })
+    } else if is_type_named(&field.ty, "ErasedGodotRef") {
+        Ok(quote! { signal.origin() })
+    } else {
+        Err(Error::new_spanned(
+            field,
+            "GodotSignal: #[origin] fields must have the type of Entity, Option<Entity> or ErasedGodotRef",
+        ))
+    }
+}
+
+/// Returns the last segment of the type's path, so that types match however they are imported
+fn last_path_segment(ty: &Type) -> Option<&PathSegment> {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => path.segments.last(),
+        _ => None,
+    }
+}
+
+fn is_type_named(ty: &Type, name: &str) -> bool {
+    matches!(
+        last_path_segment(ty),
+        Some(segment) if segment.ident == name && segment.arguments.is_empty()
+    )
+}
+
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let segment = last_path_segment(ty).filter(|segment| segment.ident == "Option")?;
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => {
+            match arguments.args.first()? {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
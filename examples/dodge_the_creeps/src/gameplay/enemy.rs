@@ -56,13 +56,13 @@ fn spawn_mob(
 
     let position = mob_spawn_path_follow.position();
 
-    let mut transform = GodotTransform2D::IDENTITY.translated(position);
-    transform.set_rotation(direction as f32);
+    let transform = Transform2D::from_translation(Vec2::new(position.x, position.y))
+        .with_rotation(direction as f32);
 
     commands
         .spawn_empty()
         .insert(Mob { direction })
-        .insert(Transform2D::from(transform))
+        .insert(transform)
         .insert(GodotScene::from_handle(&assets.mob_scn));
 }
 
@@ -105,7 +105,8 @@ fn new_mob(
             &mut mob_nodes.visibility_notifier,
             "screen_exited",
             &mut scene_tree,
-        );
+        )
+        .unwrap();
     }
 }
 
@@ -84,9 +84,9 @@ fn move_player(
         Vector2::ZERO
     };
 
-    transform.origin += velocity * system_delta.delta_seconds();
-    transform.origin.x = f32::min(f32::max(0.0, transform.origin.x), screen_size.x);
-    transform.origin.y = f32::min(f32::max(0.0, transform.origin.y), screen_size.y);
+    transform.translation += Vec2::new(velocity.x, velocity.y) * system_delta.delta_seconds();
+    transform.translation.x = f32::min(f32::max(0.0, transform.translation.x), screen_size.x);
+    transform.translation.y = f32::min(f32::max(0.0, transform.translation.y), screen_size.y);
 
     if velocity.x != 0.0 {
         animated_sprite.set_animation("right");
@@ -113,7 +113,7 @@ fn setup_player(
         .unwrap()
         .get::<Node2D>()
         .position();
-    transform.origin = start_position;
+    transform.translation = Vec2::new(start_position.x, start_position.y);
 }
 
 fn check_player_death(
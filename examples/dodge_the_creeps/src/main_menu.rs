@@ -21,6 +21,7 @@ impl Plugin for MainMenuPlugin {
             )
                 .in_schedule(OnExit(GameState::Loading)),
         )
+        .add_plugin(TypedGodotSignalPlugin::<PlayButtonPressed>::default())
         .add_system(listen_for_play_button.in_set(OnUpdate(GameState::MainMenu)))
         .add_system(hide_play_button.in_schedule(OnExit(GameState::MainMenu)))
         .add_system(show_play_button.in_schedule(OnEnter(GameState::MainMenu)));
@@ -53,17 +54,20 @@ fn connect_play_button(
     mut scene_tree: SceneTreeRef,
 ) {
     let play_button = assets.get_mut(&menu_assets.play_button).unwrap();
-    connect_godot_signal(play_button, "pressed", &mut scene_tree);
+    connect_godot_signal(play_button, "pressed", &mut scene_tree).unwrap();
 }
 
+#[derive(GodotSignal)]
+#[signal("pressed")]
+pub struct PlayButtonPressed;
+
 fn listen_for_play_button(
-    mut events: EventReader<GodotSignal>,
+    mut events: EventReader<PlayButtonPressed>,
     mut app_state: ResMut<NextState<GameState>>,
 ) {
-    for evt in events.iter() {
-        if evt.name() == "pressed" {
-            app_state.set(GameState::Countdown);
-        }
+    if !events.is_empty() {
+        events.clear();
+        app_state.set(GameState::Countdown);
     }
 }
 
@@ -1,7 +1,6 @@
 #![allow(clippy::type_complexity)]
 
 use bevy_godot::prelude::*;
-use gdnative::api::CSGBox;
 
 fn init(_handle: &InitHandle) {}
 
@@ -32,25 +31,15 @@ pub struct CubeSpawnTimer(pub Timer);
 #[derive(Resource)]
 pub struct PrintEntitiesTimer(pub Timer);
 
-fn spawn_cube(
-    mut commands: Commands,
-    mut scene_tree: SceneTreeRef,
-    mut timer: ResMut<CubeSpawnTimer>,
-    time: Res<Time>,
-) {
+fn spawn_cube(mut commands: Commands, mut timer: ResMut<CubeSpawnTimer>, time: Res<Time>) {
     timer.0.tick(time.delta());
     if timer.0.just_finished() {
-        let csg_node = CSGBox::new();
-        let mut csg_node = unsafe { ErasedGodotRef::new(csg_node) };
-
-        scene_tree.add_to_scene(csg_node.get::<Node>());
-
         commands
             .spawn_empty()
             .insert(Cube {
                 lifetime: Timer::from_seconds(3.0, TimerMode::Once),
             })
-            .insert(csg_node)
+            .insert(SpawnGodotNode::new("CSGBox"))
             .insert(Transform::from(BevyTransform::from_translation(Vec3::new(
                 10.0 * time.elapsed_seconds().sin(),
                 5.0 * time.elapsed_seconds().sin(),
@@ -59,11 +48,11 @@ fn spawn_cube(
     }
 }
 
-fn cube_lifetime(mut cubes: Query<(&mut Cube, &mut ErasedGodotRef)>, time: Res<Time>) {
-    for (mut cube, mut reference) in cubes.iter_mut() {
+fn cube_lifetime(mut commands: Commands, mut cubes: Query<(Entity, &mut Cube)>, time: Res<Time>) {
+    for (ent, mut cube) in cubes.iter_mut() {
         cube.lifetime.tick(time.delta());
         if cube.lifetime.finished() {
-            reference.get::<Node>().queue_free();
+            commands.entity(ent).despawn();
         }
     }
 }